@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CardID {
     Baby, // 2019.12.24
     Basic, // 2019.12.24
@@ -35,4 +36,7 @@ pub enum CardID {
     ResetButton, // 2019.12.31
 
     UnfairBargain, // 2020.01.01
+
+    Neigh, // 2026.10.18
+    SuperNeigh, // 2026.10.18
 }
\ No newline at end of file
@@ -1,11 +1,9 @@
-use crate::{Card, CardID};
+use crate::{Card, CardID, CardUuid, GameError, Location};
 
 pub trait CardList {
     /// Internal functions to work around the fact that traits can't have members
     fn __list(&self) -> &Vec<Box<dyn Card>>;
     fn __list_mut(&mut self) -> &mut Vec<Box<dyn Card>>;
-    fn __processing(&self) -> Option<u64>;
-    fn __processing_mut(&mut self, processing: Option<u64>) -> Option<u64>;
 
     /// Add a card to this stable
     fn add_card(&mut self, card: Box<dyn Card>) {
@@ -13,35 +11,35 @@ pub trait CardList {
     }
 
     /// Take the card with the given uuid
-    fn take_card(&mut self, uuid: u64) -> Option<Box<dyn Card>> {
+    fn take_card(&mut self, uuid: CardUuid) -> Option<Box<dyn Card>> {
         self.__list().iter().position(|c| c.uuid() == uuid).map(|index| self.__list_mut().swap_remove(index))
     }
 
-    /// Take the card with the given uuid to process it
-    /// You MUST call readd_card after this function
-    fn process_card(&mut self, uuid: u64) -> Option<Box<dyn Card>> {
-        assert!(self.__processing_mut(Some(uuid)).is_none());
-        self.take_card(uuid)
+    /// Take the card with the given uuid, or give an error if this list (at the given location)
+    /// doesn't have it
+    fn try_take_card(&mut self, uuid: CardUuid, location: &Location) -> Result<Box<dyn Card>, GameError> {
+        self.take_card(uuid).ok_or_else(|| GameError::UnknownCard { uuid, location: location.clone() })
     }
 
-    /// Re-add the card after processing
-    fn readd_card(&mut self, card: Box<dyn Card>) {
-        assert!(self.__processing_mut(None).is_some());
-        self.__list_mut().push(card)
+    /// Put the cards in the order of the given UUIDs
+    fn reorder(&mut self, order: &[CardUuid]) {
+        self.__list_mut().sort_by_key(|card| order.iter().position(|&uuid| uuid == card.uuid()));
     }
 
     /// Get the card with a given uuid
-    fn get_card(&self, uuid: u64) -> Option<&Box<dyn Card>> {
+    #[allow(clippy::borrowed_box)]
+    fn get_card(&self, uuid: CardUuid) -> Option<&Box<dyn Card>> {
         self.__list().iter().find(|c| c.uuid() == uuid)
     }
 
     /// Get the card with a given ID
+    #[allow(clippy::borrowed_box)]
     fn get_id_card(&self, id: CardID) -> Option<&Box<dyn Card>> {
         self.__list().iter().find(|c| c.id() == id)
     }
 
     /// Get the card with a given uuid mutably
-    fn get_card_mut(&mut self, uuid: u64) -> Option<&mut Box<dyn Card>> {
+    fn get_card_mut(&mut self, uuid: CardUuid) -> Option<&mut Box<dyn Card>> {
         self.__list_mut().iter_mut().find(|c| c.uuid() == uuid)
     }
 
@@ -67,12 +65,8 @@ pub trait CardList {
     }
 
     /// Get a vector of all uuids from all cards
-    fn uuids(&self) -> Vec<u64> {
-        let mut uuids: Vec<u64> = self.__list().iter().map(|card| card.uuid()).collect();
-        if let Some(uuid) = self.__processing() {
-            uuids.push(uuid);
-        }
-        uuids
+    fn uuids(&self) -> Vec<CardUuid> {
+        self.__list().iter().map(|card| card.uuid()).collect()
     }
 
     /// Get a vector of all ids from all cards matching the given filter
@@ -81,7 +75,7 @@ pub trait CardList {
     }
 
     /// Get a vector of all uuids from all cards matching the given filter
-    fn filter_uuids(&self, filter: fn(card: &dyn Card) -> bool) -> Vec<u64> {
+    fn filter_uuids(&self, filter: fn(card: &dyn Card) -> bool) -> Vec<CardUuid> {
         self.__list().iter().filter(|card| filter(&***card)).map(|card| card.uuid()).collect()
     }
 
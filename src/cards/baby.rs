@@ -1,21 +1,22 @@
 use crate::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BabyTheme {
     Skeleton, Narwhal, Rainbow, White, Black, Yellow, Green, Blue, Red, Purple, LightPink, Pink, Hotpink,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Baby {
-    uuid: u64,
+    uuid: CardUuid,
     theme: BabyTheme,
 }
 
 impl Baby {
-    pub fn new(theme: BabyTheme) -> Baby {
+    pub fn new(uuid: CardUuid, theme: BabyTheme) -> Baby {
         Baby {
-            uuid: uuid(),
-            theme: theme,
+            uuid,
+            theme,
         }
     }
 
@@ -26,23 +27,24 @@ impl Baby {
 }
 
 impl Card for Baby {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
+    fn state(&self) -> CardState { CardState::Baby(self.theme) }
 
     fn name(&self) -> &'static str { if self.theme == BabyTheme::Narwhal { "Baby Narwhal" } else { "Baby Unicorn" } }
     fn id(&self) -> CardID { CardID::Baby }
     fn description(&self) -> &'static str { "If this card would be sacrificed, destroyed, or returned to your hand, return it to the Nursery instead." }
     fn cardtype(&self) -> CardType { CardType::Unicorn(Unicorn::Baby) }
 
-    fn on_destroy(&mut self, from: Destination, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_destroy(&mut self, from: Destination, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
         Ok(vec!{ delta_same_player!(self.uuid(), player.uuid(), from => Nursery) })
     }
 
-    fn on_sacrifice(&mut self, from: Destination, player: &Player, game: &Game) -> ResDeltas {
+    fn on_sacrifice(&mut self, from: Destination, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
         // Same as destroyed
-        self.on_destroy(from, player, game)
+        self.on_destroy(from, player, game, decider)
     }
 
-    fn on_return_to_hand(&mut self, from: Destination, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_return_to_hand(&mut self, from: Destination, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
         Ok(vec!{ delta_same_player!(self.uuid(), player.uuid(), from => Nursery) })
     }
 }
\ No newline at end of file
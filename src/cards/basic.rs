@@ -1,21 +1,22 @@
-use crate::{Card, CardID, CardType, Unicorn, uuid};
+use crate::{Card, CardID, CardState, CardType, CardUuid, Unicorn};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BasicTheme {
     PoppedCollars, VinylRecords, Dancer, Emoji, Beards, Hashtags, PumpkinSpice, Narwhal,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Basic {
-    uuid: u64,
+    uuid: CardUuid,
     theme: BasicTheme,
 }
 
 impl Basic {
-    pub fn new(theme: BasicTheme) -> Basic {
+    pub fn new(uuid: CardUuid, theme: BasicTheme) -> Basic {
         Basic {
-            uuid: uuid(),
-            theme: theme,
+            uuid,
+            theme,
         }
     }
 
@@ -26,7 +27,8 @@ impl Basic {
 }
 
 impl Card for Basic {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
+    fn state(&self) -> CardState { CardState::Basic(self.theme) }
     fn name(&self) -> &'static str { if self.theme == BasicTheme::Narwhal { "Narwhal" } else { "Basic Unicorn" } }
     fn id(&self) -> CardID { CardID::Basic }
     fn cardtype(&self) -> CardType { CardType::Unicorn(Unicorn::Basic) }
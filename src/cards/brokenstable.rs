@@ -3,7 +3,7 @@ use crate::*;
 defcard!(BrokenStable);
 
 impl Card for BrokenStable {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Broken Stable" }
     fn id(&self) -> CardID { CardID::BrokenStable }
     fn cardtype(&self) -> CardType { CardType::Downgrade }
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(ExtraTail);
 
 impl Card for ExtraTail {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Extra Tail" }
     fn id(&self) -> CardID { CardID::ExtraTail }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
@@ -13,7 +13,7 @@ impl Card for ExtraTail {
         If this card is in your Stable at the beginning of your turn, you may DRAW an extra card."
     }
 
-    fn get_destinations(&self, _player: &Player, game: &Game) -> Vec<u64> {
+    fn get_destinations(&self, _player: &Player, game: &Game) -> Vec<PlayerUuid> {
         // Find all players who have at least one basic unicorn in their stable
         game.player_uuids().into_iter().filter(
             |&player_uuid| game.p(player_uuid).stable().any(
@@ -22,7 +22,7 @@ impl Card for ExtraTail {
         ).collect()
     }
 
-    fn on_bot(&mut self, player: &Player, _game: &Game) -> ResDeltas {
-        Ok(vec!{ delta_update_draw_number!(self.uuid(), player.uuid(), Stable => 1) })
+    fn on_bot(&mut self, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
+        Ok(vec!{ delta_draw_number!(player.uuid() => By(1)) })
     }
 }
\ No newline at end of file
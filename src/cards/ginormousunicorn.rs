@@ -3,7 +3,7 @@ use crate::*;
 defcard!(GinormousUnicorn);
 
 impl Card for GinormousUnicorn {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Ginormous Unicorn" }
     fn id(&self) -> CardID { CardID::GinormousUnicorn }
     fn cardtype(&self) -> CardType { CardType::Unicorn(Unicorn::Magical) }
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(GlitterBomb);
 
 impl Card for GlitterBomb {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Glitter Bomb" }
     fn id(&self) -> CardID { CardID::GlitterBomb }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
@@ -13,27 +13,28 @@ impl Card for GlitterBomb {
         you may SACRIFICE a card. If you do, DESTROY a card."
     }
 
-    fn on_bot(&mut self, player: &Player, game: &Game) -> ResDeltas {
-        if user_choose_bool("Do you want to activate the Glitter Bomb?") {
-            // Get card to sacrifice
-            let mut card_uuids = player.stable().uuids();
+    fn on_bot(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
+        // There must be something to destroy
+        let target_uuids: Vec<PlayerUuid> = game.player_uuids_without(player.uuid()).into_iter().filter(|&player_uuid| {
+            !game.p(player_uuid).stable().destroyable_uuids(|_| true).is_empty()
+        }).collect();
 
-            // Also include the current card
-            card_uuids.push(self.uuid());
+        if !target_uuids.is_empty() && user_choose_bool(decider, "Do you want to activate the Glitter Bomb?") {
+            // Get card to sacrifice, which may also be the current card
+            let card_uuids = player.stable().uuids();
 
-            let card_uuid = *user_choose("Which card do you want to SACRIFICE?", &card_uuids);
+            let card_uuid = user_choose(decider, "Which card do you want to SACRIFICE?", &card_uuids)?;
 
             // Get player to destroy
-            let target_uuids = game.player_uuids_without(player.uuid());
-            let target_uuid = *user_choose("From who do you want to DESTROY a card?", &target_uuids);
+            let target_uuid = user_choose(decider, "From who do you want to DESTROY a card?", &target_uuids)?;
 
             // Get card to destroy
-            let target_card_uuids = game.p(target_uuid).stable().uuids();
-            let target_card_uuid = *user_choose("Which card do you want to DESTROY?", &target_card_uuids);
+            let target_card_uuids = game.p(target_uuid).stable().destroyable_uuids(|_| true);
+            let target_card_uuid = user_choose(decider, "Which card do you want to DESTROY?", &target_card_uuids)?;
 
             Ok(vec!{
                 // Sacrifice card
-                delta_same_player!(card_uuid, player.uuid(), Stable => Discard),
+                delta_same_player!(card_uuid, player.uuid(), Stable => Discard, Sacrifice),
 
                 // Destroy card
                 delta_same_player!(target_card_uuid, target_uuid, Stable => Discard),
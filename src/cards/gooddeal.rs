@@ -2,44 +2,52 @@ use crate::*;
 
 defcard!(GoodDeal);
 
+/// The follow-up step in which the player discards a card
+const DISCARD: u64 = 1;
+
 impl Card for GoodDeal {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Good Deal" }
     fn id(&self) -> CardID { CardID::GoodDeal }
     fn cardtype(&self) -> CardType { CardType::Magic }
 
     fn description(&self) -> &'static str { "DRAW 3 cards and DISCARD a card" }
 
-    fn on_play(&mut self, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_play(&mut self, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
         let mut actions = vec!{};
 
         // Move this card to the discard pile
-        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard));
+        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard, Play));
 
         // Draw 3 cards
         for _ in 0..3 {
-            // card_uuid=0 means we draw a random card from the pile
-            actions.push(delta_same_player!(0, player.uuid(), Drawpile => Hand));
+            // CardUuid::RANDOM means we draw a random card from the pile
+            actions.push(delta_same_player!(CardUuid::RANDOM, player.uuid(), Drawpile => Hand));
         }
 
-        // Jump to the discard stage of this card
+        // Discard a card after drawing
         // Despite the fact that the card is in the Discard pile, we still need to give
-        // the player UUID to be able to run on_play_stage with the right player!
-        actions.push(delta_stage!(self.uuid(), player.uuid(), Discard => CardStage::A as u64));
+        // the player UUID to be able to run on_continue with the right player!
+        actions.push(delta_continue!(self.uuid(), player.uuid(), Discard => DISCARD));
 
         Ok(actions)
     }
 
-    fn on_play_stage(&mut self, player: &Player, _game: &Game, stage: u64) -> ResDeltas {
-        match CardStage::from(stage) {
-            CardStage::A => {
-                let card_uuid = *user_choose("Which card do you want to discard?", &player.hand().uuids()); // SAFE
+    fn on_continue(&mut self, player: &Player, _game: &Game, decider: &mut dyn Decider, continuation: &Continuation) -> ResActions {
+        match continuation.step {
+            DISCARD => {
+                // Fizzle if there is no card to discard
+                if player.hand().is_empty() {
+                    return Ok(vec!{ delta_fizzle!(self.uuid(), player.uuid(), Discard) });
+                }
+
+                let card_uuid = user_choose(decider, "Which card do you want to discard?", &player.hand().uuids())?; // SAFE
 
                 // Move the chosen card to the discard pile
                 Ok(vec!{ delta_same_player!(card_uuid, player.uuid(), Hand => Discard) })
             },
 
-            _ => unreachable!("Stage not used by card"),
+            _ => Err(GameError::NotAllowed { reason: "Step not used by card" }),
         }
     }
 }
\ No newline at end of file
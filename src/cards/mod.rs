@@ -31,6 +31,9 @@ pub use nannycam::NannyCam;
 mod narwhaltorpedo;
 pub use narwhaltorpedo::NarwhalTorpedo;
 
+mod neigh;
+pub use neigh::Neigh;
+
 mod rainbowaura;
 pub use rainbowaura::RainbowAura;
 
@@ -55,6 +58,9 @@ pub use slowdown::Slowdown;
 mod summoningritual;
 pub use summoningritual::SummoningRitual;
 
+mod superneigh;
+pub use superneigh::SuperNeigh;
+
 mod targeteddestruction;
 pub use targeteddestruction::TargetedDestruction;
 
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(NannyCam);
 
 impl Card for NannyCam {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Nannycam" }
     fn id(&self) -> CardID { CardID::NannyCam }
     fn cardtype(&self) -> CardType { CardType::Downgrade }
@@ -0,0 +1,18 @@
+use crate::*;
+
+defcard!(Neigh);
+
+impl Card for Neigh {
+    fn uuid(&self) -> CardUuid { self.uuid }
+    fn name(&self) -> &'static str { "Neigh" }
+    fn id(&self) -> CardID { CardID::Neigh }
+    fn cardtype(&self) -> CardType { CardType::Instant }
+
+    fn description(&self) -> &'static str {
+        "Play this card when another player tries to play a card. \
+        Stop their card from being played and send it to the discard pile."
+    }
+
+    // Stopping the other card is handled by Game::reaction_window because it's about
+    // the card being played, not about this card
+}
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(RainbowAura);
 
 impl Card for RainbowAura {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Rainbow Aura" }
     fn id(&self) -> CardID { CardID::RainbowAura }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(RainbowMane);
 
 impl Card for RainbowMane {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Rainbow Mane" }
     fn id(&self) -> CardID { CardID::RainbowMane }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
@@ -14,7 +14,7 @@ impl Card for RainbowMane {
         a Basic Unicorn card from your hand diredtly into your Stable."
     }
 
-    fn get_destinations(&self, _player: &Player, game: &Game) -> Vec<u64> {
+    fn get_destinations(&self, _player: &Player, game: &Game) -> Vec<PlayerUuid> {
         // Find all players who have at least one basic unicorn in their stable
         game.player_uuids().into_iter().filter(
             |&player_uuid| game.p(player_uuid).stable().any(
@@ -23,11 +23,11 @@ impl Card for RainbowMane {
         ).collect()
     }
 
-    fn on_bot(&mut self, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_bot(&mut self, player: &Player, _game: &Game, decider: &mut dyn Decider) -> ResActions {
         let card_uuids = player.hand().filter_uuids(|card| card.cardtype() == CardType::Unicorn(Unicorn::Basic));
 
-        if card_uuids.len() >= 1 && user_choose_bool("Do you want to activate the Rainbow Mane?") {
-            let card_uuid = *user_choose("Which basic unicorn do you want to put in your stable?", &card_uuids);
+        if !card_uuids.is_empty() && user_choose_bool(decider, "Do you want to activate the Rainbow Mane?") {
+            let card_uuid = user_choose(decider, "Which basic unicorn do you want to put in your stable?", &card_uuids)?;
 
             Ok(vec!{ delta_same_player!(card_uuid, player.uuid(), Hand => Stable) })
         } else {
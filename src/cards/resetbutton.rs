@@ -2,9 +2,12 @@ use crate::*;
 
 defcard!(ResetButton);
 
+/// The follow-up step in which the discard pile is shuffled into the deck
+const SHUFFLE: u64 = 1;
+
 
 impl Card for ResetButton {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Reset Button" }
     fn id(&self) -> CardID { CardID::ResetButton }
     fn cardtype(&self) -> CardType { CardType::Magic }
@@ -14,40 +17,40 @@ impl Card for ResetButton {
         Shuffle the discard pile into the deck."
     }
 
-    fn on_play(&mut self, player: &Player, game: &Game) -> ResDeltas {
+    fn on_play(&mut self, player: &Player, game: &Game, _decider: &mut dyn Decider) -> ResActions {
         let mut actions = vec! {};
 
         // Discard the current card
-        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard));
+        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard, Play));
 
         // Move all Upgrades and Downgrades from all players to the Discard pile
-        actions.append(&mut game.player_uuids().into_iter().map(
+        actions.append(&mut game.player_uuids().into_iter().flat_map(
             |player_uuid| game.p(player_uuid).stable().filter_uuids(
                 |card| card.cardtype() == CardType::Upgrade || card.cardtype() == CardType::Downgrade
             ).into_iter().map(
-                |card_uuid| delta_same_player!(card_uuid, player_uuid, Stable => Discard)
-            ).collect::<Vec<Delta>>()
-        ).flatten().collect::<Vec<Delta>>()); // @TODO: Flatten
+                move |card_uuid| delta_same_player!(card_uuid, player_uuid, Stable => Discard, Sacrifice)
+            )
+        ).collect::<Vec<Action>>());
 
-        // Go to stage 2
-        actions.push(delta_stage!(self.uuid(), player.uuid(), Discard => CardStage::A as u64));
+        // Shuffle once all the sacrifices have resolved
+        actions.push(delta_continue!(self.uuid(), player.uuid(), Discard => SHUFFLE));
 
         Ok(actions)
     }
 
-    fn on_play_stage(&mut self, _player: &Player, game: &Game, stage: u64) -> ResDeltas {
-        println!("-------------------on_play_stage------------------");
-        println!("{:?}", game);
-
-        match CardStage::from(stage) {
-            CardStage::A => {
+    fn on_continue(&mut self, _player: &Player, game: &Game, _decider: &mut dyn Decider, continuation: &Continuation) -> ResActions {
+        match continuation.step {
+            SHUFFLE => {
                 // Shuffle the discard pile into the drawpile
-                Ok(game.table.discard.uuids().into_iter().map(
-                    |card_uuid| delta_same_player!(card_uuid, 0, Discard => Drawpile)
-                ).collect())
+                let mut actions: Vec<Action> = game.table.discard.uuids().into_iter().map(
+                    |card_uuid| delta_same_player!(card_uuid, PlayerUuid(0), Discard => Drawpile)
+                ).collect();
+                actions.push(Action::Shuffle);
+
+                Ok(actions)
             },
 
-            _ => unreachable!("Stage not used by card"),
+            _ => Err(GameError::NotAllowed { reason: "Step not used by card" }),
         }
     }
 }
\ No newline at end of file
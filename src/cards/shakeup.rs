@@ -3,14 +3,14 @@ use crate::*;
 defcard!(ShakeUp);
 
 impl Card for ShakeUp {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Shake Up" }
     fn id(&self) -> CardID { CardID::ShakeUp }
     fn cardtype(&self) -> CardType { CardType::Magic }
 
     fn description(&self) -> &'static str { "Shuffle this card, your hand, and the discard pile into the deck. DRAW 5 cards." }
 
-    fn on_play(&mut self, player: &Player, game: &Game) -> ResDeltas {
+    fn on_play(&mut self, player: &Player, game: &Game, _decider: &mut dyn Decider) -> ResActions {
         let mut actions = vec!{};
 
         // Move this card to the drawpile
@@ -24,9 +24,12 @@ impl Card for ShakeUp {
         actions.append(&mut game.table.discard.uuids().iter()
             .map(|&card_uuid| delta_same_player!(card_uuid, player.uuid(), Discard => Drawpile)).collect());
 
+        // Shuffle them all together
+        actions.push(Action::Shuffle);
+
         // Make the player draw 5 cards
         for _ in 0..5 {
-            actions.push(delta_same_player!(0, player.uuid(), Drawpile => Hand));
+            actions.push(delta_same_player!(CardUuid::RANDOM, player.uuid(), Drawpile => Hand));
         }
 
         Ok(actions)
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(Slowdown);
 
 impl Card for Slowdown {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Slowdown" }
     fn id(&self) -> CardID { CardID::Slowdown }
     fn cardtype(&self) -> CardType { CardType::Downgrade }
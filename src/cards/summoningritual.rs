@@ -2,9 +2,12 @@ use crate::*;
 
 defcard!(SummoningRitual);
 
+/// The follow-up step in which a unicorn is revived from the discard pile
+const REVIVE: u64 = 1;
+
 
 impl Card for SummoningRitual {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Summoning Ritual" }
     fn id(&self) -> CardID { CardID::SummoningRitual }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
@@ -15,22 +18,22 @@ impl Card for SummoningRitual {
         directly from the discard pile into your Stable."
     }
 
-    fn on_bot(&mut self, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_bot(&mut self, player: &Player, _game: &Game, decider: &mut dyn Decider) -> ResActions {
         // Check if user has two unicorn cards
         let discard_uuids = player.hand().filter_uuids(is_unicorn);
 
         // Check if the user wants to play
-        if discard_uuids.len() >= 2 && user_choose_bool("Do you want to activate the Summoning Ritual?") {
+        if discard_uuids.len() >= 2 && user_choose_bool(decider, "Do you want to activate the Summoning Ritual?") {
 
             // Discard two unicorn cards
-            let discard_uuids: Vec<u64> = user_choose_n_copy("Which cards do you want to discard?", &discard_uuids, 2); // SAFE
+            let discard_uuids: Vec<CardUuid> = user_choose_n(decider, "Which cards do you want to discard?", &discard_uuids, 2)?; // SAFE
 
-            let mut actions: Vec<Delta> = discard_uuids.iter().map(|&card_uuid|
+            let mut actions: Vec<Action> = discard_uuids.iter().map(|&card_uuid|
                 delta_same_player!(card_uuid, player.uuid(), Hand => Discard)
             ).collect();
 
-            // Go to second stage
-            actions.push(delta_stage!(self.uuid(), player.uuid(), Stable => CardStage::A as u64));
+            // Revive a unicorn once the discards have resolved
+            actions.push(delta_continue!(self.uuid(), player.uuid(), Stable => REVIVE));
 
             Ok(actions)
         } else {
@@ -38,21 +41,23 @@ impl Card for SummoningRitual {
         }
     }
 
-    fn on_play_stage(&mut self, player: &Player, game: &Game, stage: u64) -> ResDeltas {
-        match CardStage::from(stage) {
-            CardStage::A => {
+    fn on_continue(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider, continuation: &Continuation) -> ResActions {
+        match continuation.step {
+            REVIVE => {
                 let card_uuids = game.table.discard.filter_uuids(is_unicorn);
 
-                // @TODO: Cancel action if not allowed
-                assert!(card_uuids.len() >= 1);
+                // Fizzle if there is no unicorn to revive
+                if card_uuids.is_empty() {
+                    return Ok(vec!{ delta_fizzle!(self.uuid(), player.uuid(), Stable) });
+                }
 
                 // Revive a card from the discard pile
-                let card_uuid = *user_choose("Which card do you want to revive?", &card_uuids); // SAFE
+                let card_uuid = user_choose(decider, "Which card do you want to revive?", &card_uuids)?; // SAFE
 
                 Ok(vec!{ delta_same_player!(card_uuid, player.uuid(), Discard => Stable) })
             },
 
-            _ => unreachable!("Stage not used by card"),
+            _ => Err(GameError::NotAllowed { reason: "Step not used by card" }),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use crate::*;
+
+defcard!(SuperNeigh);
+
+impl Card for SuperNeigh {
+    fn uuid(&self) -> CardUuid { self.uuid }
+    fn name(&self) -> &'static str { "Super Neigh" }
+    fn id(&self) -> CardID { CardID::SuperNeigh }
+    fn cardtype(&self) -> CardType { CardType::Instant }
+
+    fn description(&self) -> &'static str {
+        "Play this card when another player tries to play a card. \
+        Stop their card from being played and send it to the discard pile. \
+        This card cannot be Neigh'd."
+    }
+
+    fn may_be_neighed(&self) -> bool { false }
+}
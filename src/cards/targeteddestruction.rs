@@ -3,38 +3,52 @@ use crate::*;
 defcard!(TargetedDestruction);
 
 impl Card for TargetedDestruction {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Targeted Destruction" }
     fn id(&self) -> CardID { CardID::TargetedDestruction }
     fn cardtype(&self) -> CardType { CardType::Magic }
 
     fn description(&self) -> &'static str { "SACRIFICE or DESTROY an Upgrade or Downgrade card" }
 
-    fn get_targets(&self, _player: &Player, game: &Game) -> Vec<u64> {
+    fn get_targets(&self, player: &Player, game: &Game) -> Vec<PlayerUuid> {
         // Find the UUIDs of all players that have at least one upgrade or downgrade in their stable
+        // The cards of the player are sacrificed, the ones of other players must be allowed to be destroyed
         game.player_uuids().into_iter().filter(|&player_uuid| {
-            game.p(player_uuid).stable().any(|card| {
-                use CardType::*;
-                card.cardtype() == Upgrade || card.cardtype() == Downgrade
-            })
+            !target_uuids(player, game.p(player_uuid)).is_empty()
         }).collect()
     }
 
-    fn on_play(&mut self, player: &Player, game: &Game) -> ResDeltas {
+    fn on_play(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
         let target_uuids = self.get_targets(player, game);
-        let target_uuid = *user_choose("From which player do you want to destroy a card?", &target_uuids); // SAFE
+        let target_uuid = user_choose(decider, "From which player do you want to destroy a card?", &target_uuids)?; // SAFE
         let target = game.p(target_uuid);
 
         // Select upgrade or downgrade from that player
-        let card_uuids = target.stable().filter_uuids(|card| card.cardtype() == CardType::Upgrade || card.cardtype() == CardType::Downgrade);
-        let card_uuid = *user_choose("Which card do you want to discard?", &card_uuids); // SAFE
+        let card_uuids = self::target_uuids(player, target);
+        let card_uuid = user_choose(decider, "Which card do you want to discard?", &card_uuids)?; // SAFE
+
+        // Cards of the player are sacrificed instead of destroyed
+        let reason = if target_uuid == player.uuid() { Reason::Sacrifice } else { Reason::Destroy };
 
         Ok(vec!{
             // Move this card to the discard pile
-            delta_same_player!(self.uuid(), player.uuid(), Hand => Discard),
+            delta_same_player!(self.uuid(), player.uuid(), Hand => Discard, Play),
 
             // Move target's card to the discard pile
-            delta_same_player!(card_uuid, target_uuid, Stable => Discard),
+            delta!(card_uuid, [target_uuid, Stable] => [target_uuid, Discard], reason),
         })
     }
-}
\ No newline at end of file
+}
+
+/// Get the upgrades and downgrades that the player may sacrifice or destroy from the target's stable
+fn target_uuids(player: &Player, target: &Player) -> Vec<CardUuid> {
+    fn is_upgrade_or_downgrade(card: &dyn Card) -> bool {
+        card.cardtype() == CardType::Upgrade || card.cardtype() == CardType::Downgrade
+    }
+
+    if target.uuid() == player.uuid() {
+        target.stable().filter_uuids(is_upgrade_or_downgrade)
+    } else {
+        target.stable().destroyable_uuids(is_upgrade_or_downgrade)
+    }
+}
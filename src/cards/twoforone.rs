@@ -2,9 +2,15 @@ use crate::*;
 
 defcard!(TwoForOne);
 
+/// The follow-up step in which a card is destroyed, carrying the cards destroyed so far
+const DESTROY: u64 = 1;
+
+/// The number of cards to destroy
+const DESTROY_COUNT: usize = 2;
+
 
 impl Card for TwoForOne {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Two-For-One" }
     fn id(&self) -> CardID { CardID::TwoForOne }
     fn cardtype(&self) -> CardType { CardType::Magic }
@@ -16,54 +22,63 @@ impl Card for TwoForOne {
     fn may_be_played_by(&self, player: &Player, game: &Game) -> bool {
         // Need to have at least 1 card in the player's stable and at least 2 cards in total in the stables of others
         !player.stable().is_empty() && game.player_uuids_without(player.uuid()).iter().map(|&player_uuid| {
-            game.p(player_uuid).stable().len()
+            game.p(player_uuid).stable().destroyable_uuids(|_| true).len()
         }).sum::<usize>() >= 2
     }
 
-    fn on_play(&mut self, player: &Player, _game: &Game) -> ResDeltas {
+    fn get_targets(&self, player: &Player, game: &Game) -> Vec<PlayerUuid> {
+        // Find the UUIDs of all other players that have a card in their stable that may be destroyed
+        game.player_uuids_without(player.uuid()).into_iter().filter(|&player_uuid| {
+            !game.p(player_uuid).stable().destroyable_uuids(|_| true).is_empty()
+        }).collect()
+    }
+
+    fn on_play(&mut self, player: &Player, _game: &Game, decider: &mut dyn Decider) -> ResActions {
         let mut actions = vec! {};
 
         let card_uuids = player.stable().uuids();
-        let card_uuid = *user_choose("Which card do you want to sacrifice?", &card_uuids);
+        let card_uuid = user_choose(decider, "Which card do you want to sacrifice?", &card_uuids)?;
 
         // Sacrifice card
-        actions.push(delta_same_player!(card_uuid, player.uuid(), Stable => Discard));
+        actions.push(delta_same_player!(card_uuid, player.uuid(), Stable => Discard, Sacrifice));
 
         // Discard the card because it's played
-        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard));
+        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard, Play));
 
-        // Go to stage 2
-        actions.push(delta_stage!(self.uuid(), player.uuid(), Discard => CardStage::A as u64));
+        // Destroy the cards once the sacrifice has resolved
+        actions.push(delta_continue!(self.uuid(), player.uuid(), Discard => DESTROY));
 
         Ok(actions)
     }
 
-    fn on_play_stage(&mut self, player: &Player, game: &Game, stage: u64) -> ResDeltas {
-        match CardStage::from(stage) {
-            CardStage::A => {
+    fn on_continue(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider, continuation: &Continuation) -> ResActions {
+        match continuation.step {
+            DESTROY => {
+                // Fizzle if there is no card left to destroy
+                if self.get_targets(player, game).is_empty() {
+                    return Ok(vec!{ delta_fizzle!(self.uuid(), player.uuid(), Discard) });
+                }
+
                 let mut actions = vec! {};
 
-                let target_uuid = *user_choose("Whose card do you want to destroy?", &game.player_uuids_without(player.uuid()));
-                let card_uuid = *user_choose("Which card do you want to destroy?", &game.p(target_uuid).stable().uuids());
+                let target_uuid = user_choose(decider, "Whose card do you want to destroy?", &self.get_targets(player, game))?;
+                let card_uuid = user_choose(decider, "Which card do you want to destroy?", &game.p(target_uuid).stable().destroyable_uuids(|_| true))?;
 
                 // Destroy card
                 actions.push(delta_same_player!(card_uuid, target_uuid, Stable => Discard));
 
-                // Go to stage 3
-                actions.push(delta_stage!(self.uuid(), player.uuid(), Discard => CardStage::B as u64));
+                // Destroy the next card once this one has resolved
+                let mut destroyed_uuids = continuation.card_uuids.clone();
+                destroyed_uuids.push(card_uuid);
 
-                Ok(actions)
-            },
-
-            CardStage::B => {
-                let target_uuid = *user_choose("Whose card do you want to destroy?", &game.player_uuids_without(player.uuid()));
-                let card_uuid = *user_choose("Which card do you want to destroy?", &game.p(target_uuid).stable().uuids());
+                if destroyed_uuids.len() < DESTROY_COUNT {
+                    actions.push(delta_continue!(self.uuid(), player.uuid(), Discard => Continuation::new(DESTROY).with_cards(destroyed_uuids)));
+                }
 
-                // Destroy card
-                Ok(vec!{ delta_same_player!(card_uuid, target_uuid, Stable => Discard) })
+                Ok(actions)
             },
 
-            _ => unreachable!("Stage not used by card"),
+            _ => Err(GameError::NotAllowed { reason: "Step not used by card" }),
         }
     }
 }
\ No newline at end of file
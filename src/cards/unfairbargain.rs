@@ -3,14 +3,14 @@ use crate::*;
 defcard!(UnfairBargain);
 
 impl Card for UnfairBargain {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Unfair Bargain" }
     fn id(&self) -> CardID { CardID::UnfairBargain }
     fn cardtype(&self) -> CardType { CardType::Magic }
 
     fn description(&self) -> &'static str { "Trade hands with any other player" }
 
-    fn get_targets(&self, player: &Player, game: &Game) -> Vec<u64> {
+    fn get_targets(&self, player: &Player, game: &Game) -> Vec<PlayerUuid> {
         // Find the UUIDs of all players that have at least one card in their hand
         // Except for the current player
         game.player_uuids_without(player.uuid()).into_iter().filter(
@@ -18,14 +18,14 @@ impl Card for UnfairBargain {
         ).collect()
     }
 
-    fn on_play(&mut self, player: &Player, game: &Game) -> ResDeltas {
+    fn on_play(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
         let target_uuids = self.get_targets(player, game);
-        let target_uuid = *user_choose("With which player do you want to trade hands?", &target_uuids); // SAFE
+        let target_uuid = user_choose(decider, "With which player do you want to trade hands?", &target_uuids)?; // SAFE
 
         let mut actions = vec!{ };
 
         // Move this card to the discard pile
-        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard));
+        actions.push(delta_same_player!(self.uuid(), player.uuid(), Hand => Discard, Play));
 
         // Move all cards (except this one) from this player's hand to the other player
         actions.append(&mut player.hand().uuids().into_iter().filter(|&uuid| uuid != self.uuid()).map(
@@ -1,46 +1,60 @@
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnicornLasso {
-    pub uuid: u64,
+    pub uuid: CardUuid,
 
-    target_uuid: Option<u64>,
-    stolen_card_uuid: Option<u64>,
+    target_uuid: Option<PlayerUuid>,
+    stolen_card_uuid: Option<CardUuid>,
 }
 
 impl UnicornLasso {
-    pub fn new() -> UnicornLasso {
+    pub fn new(uuid: CardUuid) -> UnicornLasso {
+        UnicornLasso::with_state(uuid, None, None)
+    }
+
+    /// Build the card with a known UUID and the card it stole, eg. to restore it
+    pub fn with_state(uuid: CardUuid, target_uuid: Option<PlayerUuid>, stolen_card_uuid: Option<CardUuid>) -> UnicornLasso {
         UnicornLasso {
-            uuid: uuid(),
-            target_uuid: None,
-            stolen_card_uuid: None,
+            uuid,
+            target_uuid,
+            stolen_card_uuid,
         }
     }
 }
 
 impl Card for UnicornLasso {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Unicorn Lasso" }
     fn id(&self) -> CardID { CardID::UnicornLasso }
     fn cardtype(&self) -> CardType { CardType::Upgrade }
 
+    fn state(&self) -> CardState {
+        CardState::Steal { target_uuid: self.target_uuid, stolen_card_uuid: self.stolen_card_uuid }
+    }
+
     fn description(&self) -> &'static str {
         "If this card is in your Stable at the beginning of your turn, \
         you may STEAL a Unicorn card. At the end of your turn, return that \
         Unicorn card to the Stable from which you stole it."
     }
 
-    fn get_targets(&self, player: &Player, game: &Game) -> Vec<u64> {
+    fn get_targets(&self, player: &Player, game: &Game) -> Vec<PlayerUuid> {
         // Find all players that aren't the current player and that have at least 1 unicorn card in their stable
         game.player_uuids_without(player.uuid()).into_iter()
             .filter(|&player_uuid| game.p(player_uuid).stable().count_unicorns() > 0).collect()
     }
 
-    fn on_bot(&mut self, player: &Player, game: &Game) -> ResDeltas {
+    fn on_bot(&mut self, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
+        let target_uuids = self.get_targets(player, game);
+
+        // Do nothing if there is no unicorn to steal
+        if target_uuids.is_empty() {
+            return delta_nothing!();
+        }
 
-        if user_choose_bool("Do you want to play the Unicorn Lasso?") {
-            let target_uuids = self.get_targets(player, game);
-            let target_uuid = *user_choose("From which player do you want to STEAL a card?", &target_uuids); // SAFE
+        if user_choose_bool(decider, "Do you want to play the Unicorn Lasso?") {
+            let target_uuid = user_choose(decider, "From which player do you want to STEAL a card?", &target_uuids)?; // SAFE
             let target = game.p(target_uuid);
 
             // Make sure we don't overwrite the old target
@@ -49,21 +63,21 @@ impl Card for UnicornLasso {
 
                 // Find all unicorn cards from the target's stable
                 let card_uuids = target.stable().filter_uuids(|c| is_unicorn(c));
-                let card_uuid = *user_choose("Which card do you want to STEAL?", &card_uuids); // SAFE
+                let card_uuid = user_choose(decider, "Which card do you want to STEAL?", &card_uuids)?; // SAFE
 
                 self.stolen_card_uuid = Some(card_uuid);
 
                 // Move card from target.stable to player.stable
                 Ok(vec!{ delta!(card_uuid, [target_uuid, Stable] => [player.uuid(), Stable]) })
             } else {
-                Err("Card still in BOT phase")
+                Err(GameError::NotAllowed { reason: "Card still in BOT phase" })
             }
         } else {
             delta_nothing!()
         }
     }
 
-    fn on_eot(&mut self, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_eot(&mut self, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
         if let (Some(target_uuid), Some(stolen_card_uuid)) = (self.target_uuid, self.stolen_card_uuid) {
             // Reset fields
             self.target_uuid = None;
@@ -3,7 +3,7 @@ use crate::*;
 defcard!(UnicornPhoenix);
 
 impl Card for UnicornPhoenix {
-    fn uuid(&self) -> u64 { self.uuid }
+    fn uuid(&self) -> CardUuid { self.uuid }
     fn name(&self) -> &'static str { "Unicorn Phoenix" }
     fn id(&self) -> CardID { CardID::UnicornPhoenix }
     fn cardtype(&self) -> CardType { CardType::Unicorn(Unicorn::Magical) }
@@ -18,14 +18,19 @@ impl Card for UnicornPhoenix {
         player.hand().len() >= 1
     }
 
-    fn on_enter_stable(&mut self, _from: Destination, player: &Player, _game: &Game) -> ResDeltas {
-        let card_uuid = *user_choose("Choose a card to discard", &player.hand().uuids()); // SAFE
+    fn on_enter_stable(&mut self, _from: Destination, player: &Player, _game: &Game, decider: &mut dyn Decider) -> ResActions {
+        // Fizzle if there is nothing to discard, eg. when the card is brought back without being played
+        if player.hand().is_empty() {
+            return Ok(vec!{ delta_fizzle!(self.uuid(), player.uuid(), Stable) });
+        }
+
+        let card_uuid = user_choose(decider, "Choose a card to discard", &player.hand().uuids())?; // SAFE
 
         // Move the card from the player's hand to the discard pile
         Ok(vec!{ delta_same_player!(card_uuid, player.uuid(), Hand => Discard) })
     }
 
-    fn on_destroy(&mut self, from: Destination, player: &Player, _game: &Game) -> ResDeltas {
+    fn on_destroy(&mut self, from: Destination, player: &Player, _game: &Game, _decider: &mut dyn Decider) -> ResActions {
         if player.hand().len() >= 1 {
             // Move the card back into the stable
             Ok(vec!{ delta_same_player!(self.uuid(), player.uuid(), from => Stable) })
@@ -34,8 +39,8 @@ impl Card for UnicornPhoenix {
         }
     }
 
-    fn on_sacrifice(&mut self, from: Destination, player: &Player, game: &Game) -> ResDeltas {
+    fn on_sacrifice(&mut self, from: Destination, player: &Player, game: &Game, decider: &mut dyn Decider) -> ResActions {
         // The same happens on sacrifice as on destroy
-        self.on_destroy(from, player, game)
+        self.on_destroy(from, player, game, decider)
     }
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::{ CardUuid, PlayerUuid };
+use crate::cards::{ BabyTheme, BasicTheme };
+
+/// Everything a card remembers besides its ID and UUID
+/// Together with those, this is enough to build the exact same card again (eg. when loading a saved game)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardState {
+    /// The card doesn't remember anything
+    Stateless,
+
+    /// The theme of a Baby Unicorn
+    Baby(BabyTheme),
+
+    /// The theme of a Basic Unicorn
+    Basic(BasicTheme),
+
+    /// Which card was stolen from which player, to be able to return it later
+    /// Example: Unicorn Lasso
+    Steal { target_uuid: Option<PlayerUuid>, stolen_card_uuid: Option<CardUuid> },
+}
@@ -0,0 +1,49 @@
+use crate::{ CardUuid, PlayerUuid };
+
+/// A follow-up step of the effect of a card, see Card::on_continue
+///
+/// A card schedules a step with delta_continue! as the last of its actions, and the step resolves once the
+/// actions before it (including everything that followed from them) have been resolved. The effects of other
+/// cards that triggered at the same moment wait for it, so they keep the order the player chose.
+/// The step carries everything it needs to know from the earlier steps, so effects can be as long as needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Continuation {
+    /// Which step of the effect this is, the card decides what the number means
+    pub step: u64,
+
+    /// The cards the earlier steps passed on, eg. the card that was sacrificed in the first step
+    pub card_uuids: Vec<CardUuid>,
+
+    /// The players the earlier steps passed on, eg. the chosen target
+    pub player_uuids: Vec<PlayerUuid>,
+}
+
+impl Continuation {
+    pub fn new(step: u64) -> Continuation {
+        Continuation {
+            step,
+            card_uuids: vec!{},
+            player_uuids: vec!{},
+        }
+    }
+
+    /// Pass these cards on to the step
+    pub fn with_cards(mut self, card_uuids: Vec<CardUuid>) -> Continuation {
+        self.card_uuids = card_uuids;
+        self
+    }
+
+    /// Pass these players on to the step
+    pub fn with_players(mut self, player_uuids: Vec<PlayerUuid>) -> Continuation {
+        self.player_uuids = player_uuids;
+        self
+    }
+}
+
+/// A step without any data
+impl From<u64> for Continuation {
+    fn from(step: u64) -> Continuation {
+        Continuation::new(step)
+    }
+}
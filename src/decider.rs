@@ -0,0 +1,132 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// A Decider makes all the choices for a single player
+/// This can be a terminal UI, a network client, a bot or a scripted test
+///
+/// All options are given as UUIDs (or plain numbers), and the decider answers
+/// with the index of the option it wants to choose.
+pub trait Decider: ::std::fmt::Debug {
+    /// Choose one of the options and return its index
+    /// There is always at least one option
+    fn choose(&mut self, question: &'static str, options: &[u64]) -> usize;
+
+    /// Choose n different options and return their indices
+    /// There are always at least n options
+    fn choose_n(&mut self, question: &'static str, options: &[u64], n: usize) -> Vec<usize>;
+
+    /// Answer a yes/no question
+    fn choose_bool(&mut self, question: &'static str) -> bool;
+}
+
+/// Decider that always picks the first option(s) and answers yes to every question
+/// This is the default for every player and what the tests rely on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstChoiceDecider;
+
+impl Decider for FirstChoiceDecider {
+    fn choose(&mut self, _question: &'static str, _options: &[u64]) -> usize { 0 }
+
+    fn choose_n(&mut self, _question: &'static str, _options: &[u64], n: usize) -> Vec<usize> { (0..n).collect() }
+
+    fn choose_bool(&mut self, _question: &'static str) -> bool { true }
+}
+
+/// An answer that a decider gave, eg. to write it to the event log
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Answer {
+    /// The index of the chosen option
+    Choose(usize),
+
+    /// The indices of the chosen options
+    ChooseN(Vec<usize>),
+
+    /// The answer to a yes/no question
+    Bool(bool),
+}
+
+/// Decider that gives a list of answers in order, eg. to replay a game
+/// Once it runs out of (fitting) answers, it acts like a FirstChoiceDecider
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptedDecider {
+    answers: VecDeque<Answer>,
+}
+
+impl ScriptedDecider {
+    pub fn new(answers: Vec<Answer>) -> ScriptedDecider {
+        ScriptedDecider {
+            answers: VecDeque::from(answers),
+        }
+    }
+}
+
+impl Decider for ScriptedDecider {
+    fn choose(&mut self, question: &'static str, options: &[u64]) -> usize {
+        match self.answers.pop_front() {
+            Some(Answer::Choose(index)) if index < options.len() => index,
+            _ => FirstChoiceDecider.choose(question, options),
+        }
+    }
+
+    fn choose_n(&mut self, question: &'static str, options: &[u64], n: usize) -> Vec<usize> {
+        match self.answers.pop_front() {
+            Some(Answer::ChooseN(indices)) if indices.len() == n && indices.iter().all(|&i| i < options.len()) => indices,
+            _ => FirstChoiceDecider.choose_n(question, options, n),
+        }
+    }
+
+    fn choose_bool(&mut self, question: &'static str) -> bool {
+        match self.answers.pop_front() {
+            Some(Answer::Bool(answer)) => answer,
+            _ => FirstChoiceDecider.choose_bool(question),
+        }
+    }
+}
+
+/// Decider that remembers all answers another decider gives
+#[derive(Debug)]
+pub(crate) struct RecordingDecider {
+    pub inner: Box<dyn Decider>,
+    pub answers: Vec<Answer>,
+}
+
+impl Decider for RecordingDecider {
+    fn choose(&mut self, question: &'static str, options: &[u64]) -> usize {
+        let index = self.inner.choose(question, options);
+        self.answers.push(Answer::Choose(index));
+        index
+    }
+
+    fn choose_n(&mut self, question: &'static str, options: &[u64], n: usize) -> Vec<usize> {
+        let indices = self.inner.choose_n(question, options, n);
+        self.answers.push(Answer::ChooseN(indices.clone()));
+        indices
+    }
+
+    fn choose_bool(&mut self, question: &'static str) -> bool {
+        let answer = self.inner.choose_bool(question);
+        self.answers.push(Answer::Bool(answer));
+        answer
+    }
+}
+
+/// Decider that lets several players share a single decider
+/// This is used to make all hypothetical choices of a preview with the same decider
+#[derive(Debug, Clone)]
+pub(crate) struct SharedDecider(pub Rc<RefCell<Box<dyn Decider>>>);
+
+impl Decider for SharedDecider {
+    fn choose(&mut self, question: &'static str, options: &[u64]) -> usize {
+        self.0.borrow_mut().choose(question, options)
+    }
+
+    fn choose_n(&mut self, question: &'static str, options: &[u64], n: usize) -> Vec<usize> {
+        self.0.borrow_mut().choose_n(question, options, n)
+    }
+
+    fn choose_bool(&mut self, question: &'static str) -> bool {
+        self.0.borrow_mut().choose_bool(question)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+
+use crate::cards::{ BabyTheme, BasicTheme };
+
+use std::ops::RangeInclusive;
+
+/// A DeckSpec describes which cards are used to set up a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeckSpec {
+    /// The cards of the base game, with their official counts
+    /// Only the cards that are implemented are included
+    Base,
+}
+
+impl DeckSpec {
+    /// The number of players that can play with these cards
+    pub fn player_range(self) -> RangeInclusive<usize> {
+        match self {
+            DeckSpec::Base => 2..=8,
+        }
+    }
+
+    /// Build all cards that go into the drawpile
+    /// Their UUIDs come from the given allocator
+    pub fn deck(self, uuids: &mut UuidAllocator) -> Vec<Box<dyn Card>> {
+        match self {
+            DeckSpec::Base => {
+                use BasicTheme::*;
+
+                // All cards of the base game with their official counts, except the themed unicorns
+                let mut deck: Vec<Box<dyn Card>> = CardRegistry::expansion(Expansion::Base)
+                    .filter(|info| info.id != CardID::Baby && info.id != CardID::Basic)
+                    .flat_map(|info| (0..info.count).map(move |_| info.factory))
+                    .map(|factory| factory(uuids.card(), CardState::Stateless).unwrap())  // CONFIRMED SAFE UNWRAP
+                    .collect();
+
+                // Basic Unicorns: 3 of every theme and a single Narwhal
+                for &theme in &[PoppedCollars, VinylRecords, Dancer, Emoji, Beards, Hashtags, PumpkinSpice] {
+                    for _ in 0..3 {
+                        deck.push(Box::new(cards::Basic::new(uuids.card(), theme)));
+                    }
+                }
+                deck.push(Box::new(cards::Basic::new(uuids.card(), Narwhal)));
+
+                deck
+            },
+        }
+    }
+
+    /// Build all baby unicorns that go into the nursery
+    /// Their UUIDs come from the given allocator
+    pub fn babies(self, uuids: &mut UuidAllocator) -> Vec<Box<dyn Card>> {
+        match self {
+            DeckSpec::Base => {
+                use BabyTheme::*;
+
+                [Skeleton, Narwhal, Rainbow, White, Black, Yellow, Green, Blue, Red, Purple, LightPink, Pink, Hotpink].iter()
+                    .map(|&theme| Box::new(cards::Baby::new(uuids.card(), theme)) as Box<dyn Card>)
+                    .collect()
+            },
+        }
+    }
+}
@@ -1,17 +1,71 @@
+use crate::{ CardUuid, Continuation, PlayerUuid };
+
+/// Everything that can happen to a game, as the result of a card or of the rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Move a card from one location to another
+    Move(Delta),
+
+    /// Continue the effect of the card at the location with a follow-up step
+    Continue { card_uuid: CardUuid, location: Location, continuation: Continuation },
+
+    /// Change the number of cards the player may draw
+    DrawNumber { player_uuid: PlayerUuid, change: NumberChange },
+
+    /// Change the maximum number of cards the player may have in their hand at the end of the turn
+    HandLimit { player_uuid: PlayerUuid, change: NumberChange },
+
+    /// Show the cards at the location to the player, eg. the hand of another player
+    Reveal { location: Location, player_uuid: PlayerUuid },
+
+    /// Shuffle the drawpile
+    Shuffle,
+
+    /// The effect of the card at the location can't be completed anymore, so it fizzles and nothing happens
+    Fizzle { card_uuid: CardUuid, location: Location },
+}
+
+/// How a number like the draw number or the hand limit changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberChange {
+    /// Add the difference, which can be negative (the number never drops below 0)
+    By(i64),
+
+    /// Set the number
+    To(u64),
+}
+
+impl NumberChange {
+    /// Get the number after this change
+    pub fn apply(self, number: u64) -> u64 {
+        match self {
+            NumberChange::By(difference) => number.saturating_add_signed(difference),
+            NumberChange::To(number) => number,
+        }
+    }
+}
+
+/// A card moving from one location to another
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delta {
-    pub card_uuid: u64,
+    pub card_uuid: CardUuid,
     pub from: Location,
     pub to: Location,
+    pub reason: Reason,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
-    pub player_uuid: u64, // ignored iff destination=Discard|Drawpile|Nursery
+    pub player_uuid: PlayerUuid, // ignored iff destination=Discard|Drawpile|Nursery
     pub destination: Destination,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Destination {
     // Table
     Discard,
@@ -21,16 +75,43 @@ pub enum Destination {
     // Player
     Hand,
     Stable,
+}
+
+/// Why a card moves, so the right card hooks are called
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Reason {
+    /// The card is played, or put into a stable by a card effect
+    Play,
+    /// The card is drawn from the drawpile
+    Draw,
+    /// The card is discarded from the hand (calls on_discard)
+    Discard,
+    /// The card is destroyed from a stable (calls on_destroy)
+    Destroy,
+    /// The card is sacrificed from its own stable (calls on_sacrifice)
+    Sacrifice,
+    /// The card is taken from another player
+    Steal,
+    /// The card is returned to a hand (calls on_return_to_hand)
+    Return,
+    /// Anything else, eg. shuffling cards into the drawpile
+    Move,
+}
+
+impl Reason {
+    /// The usual reason for moving a card like this, when a delta doesn't give one
+    pub fn of(from: Destination, to: Destination) -> Reason {
+        use Destination::*;
 
-    // Special
-    /// Make the game use the actions from a different stage of the card
-    /// Only allowed in the to location
-    /// to.player_uuid contains the stage number
-    Stage,
-    /// Update the number of cards the player may draw
-    /// Only allowed in the to location
-    /// to.player_uuid contains the (non-zero) DIFFERENCE of the draw number
-    ///   (ie. +1 increases the number with 1, instead of setting it to 1)
-    /// if to.player_uuid is 0, the number of cards the user may draw is set to 0
-    UpdateDrawNumber,
+        match (from, to) {
+            (Drawpile, Hand) => Reason::Draw,
+            (Hand, Discard) => Reason::Discard,
+            (Stable, Discard) | (Stable, Nursery) => Reason::Destroy,
+            (Stable, Hand) => Reason::Return,
+            (Stable, Stable) | (Hand, Hand) => Reason::Steal,
+            (_, Stable) => Reason::Play,
+            _ => Reason::Move,
+        }
+    }
 }
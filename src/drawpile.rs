@@ -1,24 +1,34 @@
-use crate::{Card, CardList};
+use crate::{Card, CardList, CardUuid, GameError, GameRng, Location};
 
+use rand::Rng;
 use rand::seq::SliceRandom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Drawpile {
     cards: Vec<Box<dyn Card>>,
-    processing: Option<u64>,
 
     /// By default, the drawpile automatically shuffles whenever you add a card to it.
     /// However, you can disable that by setting this field to false.
     pub auto_shuffle: bool,
+
+    /// The generator for the shuffles
+    /// This uses its own stream, so the drawpile of a game can use the same seed as the game itself
+    rng: GameRng,
 }
 
 impl Drawpile {
     pub fn new() -> Drawpile {
+        Drawpile::with_seed(::rand::thread_rng().gen())
+    }
+
+    /// Make a drawpile that always shuffles the same way for the same seed
+    pub fn with_seed(seed: u64) -> Drawpile {
         Drawpile {
             cards: vec! {},
-            processing: None,
 
             auto_shuffle: true,
+            rng: GameRng::new(seed, 1),
         }
     }
 
@@ -32,17 +42,20 @@ impl Drawpile {
         }
     }
 
+    /// Shuffle the same way as a drawpile with this seed from now on
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = GameRng::new(seed, 1);
+    }
+
     /// Shuffle all cards in the drawpile
     pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut ::rand::thread_rng());
+        self.cards.shuffle(&mut self.rng);
     }
 }
 
 impl CardList for Drawpile {
     fn __list(&self) -> &Vec<Box<dyn Card>> { &self.cards }
     fn __list_mut(&mut self) -> &mut Vec<Box<dyn Card>> { &mut self.cards }
-    fn __processing(&self) -> Option<u64> { self.processing }
-    fn __processing_mut(&mut self, new: Option<u64>) -> Option<u64> { ::std::mem::replace(&mut self.processing, new) }
 
 
     fn add_card(&mut self, card: Box<dyn Card>) {
@@ -66,9 +79,9 @@ impl CardList for Drawpile {
         }
     }
 
-    /// The difference with Drawpile is that a random card is given on uuid=0
-    fn take_card(&mut self, uuid: u64) -> Option<Box<dyn Card>> {
-        if uuid != 0 {
+    /// The difference with Drawpile is that a random card is given on CardUuid::RANDOM
+    fn take_card(&mut self, uuid: CardUuid) -> Option<Box<dyn Card>> {
+        if uuid != CardUuid::RANDOM {
             // Default implementation
             self.__list().iter().position(|c| c.uuid() == uuid).map(|index| self.__list_mut().swap_remove(index))
         } else {
@@ -76,4 +89,13 @@ impl CardList for Drawpile {
             self.draw()
         }
     }
+
+    /// Drawing from an empty drawpile gives an EmptyDrawpile error instead of an unknown card
+    fn try_take_card(&mut self, uuid: CardUuid, location: &Location) -> Result<Box<dyn Card>, GameError> {
+        if uuid == CardUuid::RANDOM && self.cards.is_empty() {
+            return Err(GameError::EmptyDrawpile);
+        }
+
+        self.take_card(uuid).ok_or_else(|| GameError::UnknownCard { uuid, location: location.clone() })
+    }
 }
@@ -0,0 +1,78 @@
+use crate::{CardID, CardUuid, Delta, Location, Phase, PlayerUuid};
+
+use std::fmt;
+
+/// The errors the Game and the cards can give back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// There is no card with this UUID at the given location
+    UnknownCard { uuid: CardUuid, location: Location },
+
+    /// There is no card with this ID at the given location
+    UnknownCardID { id: CardID, location: Location },
+
+    /// There is no player with this UUID
+    UnknownPlayer(PlayerUuid),
+
+    /// The game doesn't have any players yet
+    NoPlayers,
+
+    /// The game can't be played with this number of players
+    InvalidPlayerCount(usize),
+
+    /// The rules don't allow this
+    NotAllowed { reason: &'static str },
+
+    /// The decider gave an answer to the question that isn't one of the options
+    InvalidChoice { question: &'static str },
+
+    /// There are no cards left to draw
+    EmptyDrawpile,
+
+    /// The delta can't be applied
+    InvalidDelta(Delta),
+
+    /// The action can't be done in the current phase of the turn
+    WrongPhase { expected: Phase, actual: Phase },
+
+    /// The game is already over, so nothing can happen anymore
+    GameOver,
+
+    /// There is no action that can be undone
+    NothingToUndo,
+
+    /// There is no undone action that can be redone
+    NothingToRedo,
+
+    /// Replaying the log went differently than the log says, starting at the event with this index
+    InvalidReplay { index: usize },
+
+    /// The saved game can't be read or written
+    InvalidSave { reason: String },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GameError::*;
+
+        match self {
+            UnknownCard { uuid, location } => write!(f, "There is no card {} in {:?} of player {}", uuid, location.destination, location.player_uuid),
+            UnknownCardID { id, location } => write!(f, "There is no {:?} card in {:?} of player {}", id, location.destination, location.player_uuid),
+            UnknownPlayer(uuid) => write!(f, "There is no player {}", uuid),
+            NoPlayers => write!(f, "The game doesn't have any players"),
+            InvalidPlayerCount(count) => write!(f, "The game can't be played with {} players", count),
+            NotAllowed { reason } => write!(f, "Not allowed: {}", reason),
+            InvalidChoice { question } => write!(f, "Invalid answer to \"{}\"", question),
+            EmptyDrawpile => write!(f, "There are no more cards to draw"),
+            InvalidDelta(delta) => write!(f, "Invalid delta: {:?}", delta),
+            WrongPhase { expected, actual } => write!(f, "Only allowed in the {:?} phase, not in the {:?} phase", expected, actual),
+            GameOver => write!(f, "The game is already over"),
+            NothingToUndo => write!(f, "There is nothing to undo"),
+            NothingToRedo => write!(f, "There is nothing to redo"),
+            InvalidReplay { index } => write!(f, "The replay went differently at event {} of the log", index),
+            InvalidSave { reason } => write!(f, "Invalid saved game: {}", reason),
+        }
+    }
+}
+
+impl ::std::error::Error for GameError {}
@@ -0,0 +1,439 @@
+use crate::{Action, Answer, CardUuid, Continuation, DeckSpec, Delta, Destination, Location, NumberChange, Phase, PlayerUuid, Reason};
+
+use std::fmt;
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader, BufWriter, Write };
+use std::path::Path;
+use std::str::FromStr;
+
+/// Something that happened in a game
+///
+/// The first group of events are the things that were done to the game (the commands),
+/// which are done again when replaying the game. The second group are the things that happened
+/// because of them, which are only used to check that the replay went exactly the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    // --- Commands
+
+    /// A new game was set up with this seed and these players (UUID and name) in seating order
+    /// The UUIDs of the cards are in the order the spec created them: first the babies, then the deck
+    Setup { spec: DeckSpec, seed: u64, players: Vec<(PlayerUuid, String)>, cards: Vec<CardUuid> },
+
+    /// Every player got their baby unicorn and their first cards
+    Deal,
+
+    /// The seats were shuffled into this order
+    Seats(Vec<PlayerUuid>),
+
+    /// The hand limit of the game was changed
+    SetHandLimit(u64),
+
+    /// The number of unicorns needed to win was changed
+    SetUnicornsToWin(usize),
+
+    /// The turn was given to the next player
+    AdvanceTurn,
+
+    /// The current phase was run with step
+    Step,
+
+    /// A single phase was run without step (eg. with bot or eot)
+    RunPhase(Phase),
+
+    /// The player played this card as their action
+    Play { player_uuid: PlayerUuid, card_uuid: CardUuid },
+
+    /// The player drew a card as their action
+    Draw { player_uuid: PlayerUuid },
+
+    /// These actions were applied directly
+    Apply(Vec<Action>),
+
+    /// The last action was undone
+    Undo,
+
+    /// The last undone action was redone
+    Redo,
+
+    // --- Everything that followed from the commands
+
+    /// A new turn started
+    Turn { turn: u64, player_uuid: PlayerUuid },
+
+    /// A phase of the current turn started
+    Phase(Phase),
+
+    /// A card was played (including Neighs and cards played in the Action phase)
+    CardPlayed { player_uuid: PlayerUuid, card_uuid: CardUuid },
+
+    /// The player made a choice
+    Choice { player_uuid: PlayerUuid, answer: Answer },
+
+    /// A random draw took this card
+    RandomDraw(CardUuid),
+
+    /// This action was resolved
+    Action(Action),
+}
+
+impl Event {
+    /// Is this a command, which has to be done again when replaying?
+    pub fn is_command(&self) -> bool {
+        use Event::*;
+
+        match self {
+            Setup { .. } | Deal | Seats(_) | SetHandLimit(_) | SetUnicornsToWin(_) | AdvanceTurn
+                | Step | RunPhase(_) | Play { .. } | Draw { .. } | Apply(_) | Undo | Redo => true,
+
+            Turn { .. } | Phase(_) | CardPlayed { .. } | Choice { .. } | RandomDraw(_) | Action(_) => false,
+        }
+    }
+}
+
+/// Write an event as a single line of space separated words
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Event::*;
+
+        match self {
+            Setup { spec, seed, players, cards } => {
+                write!(f, "setup {:?} {} {}", spec, seed, players.len())?;
+
+                for (uuid, name) in players {
+                    write!(f, " {} {}", uuid, escape(name))?;
+                }
+
+                write_uuids(f, cards)
+            },
+            Deal => write!(f, "deal"),
+            Seats(seats) => { write!(f, "seats")?; write_uuids(f, seats) },
+            SetHandLimit(hand_limit) => write!(f, "set_hand_limit {}", hand_limit),
+            SetUnicornsToWin(unicorns) => write!(f, "set_unicorns_to_win {}", unicorns),
+            AdvanceTurn => write!(f, "advance_turn"),
+            Step => write!(f, "step"),
+            RunPhase(phase) => write!(f, "run_phase {:?}", phase),
+            Play { player_uuid, card_uuid } => write!(f, "play {} {}", player_uuid, card_uuid),
+            Draw { player_uuid } => write!(f, "draw {}", player_uuid),
+            Apply(actions) => {
+                write!(f, "apply")?;
+
+                for action in actions {
+                    write!(f, " ")?;
+                    write_action(f, action)?;
+                }
+
+                Ok(())
+            },
+            Undo => write!(f, "undo"),
+            Redo => write!(f, "redo"),
+
+            Turn { turn, player_uuid } => write!(f, "turn {} {}", turn, player_uuid),
+            Phase(phase) => write!(f, "phase {:?}", phase),
+            CardPlayed { player_uuid, card_uuid } => write!(f, "card_played {} {}", player_uuid, card_uuid),
+            Choice { player_uuid, answer: Answer::Choose(index) } => write!(f, "choose {} {}", player_uuid, index),
+            Choice { player_uuid, answer: Answer::ChooseN(indices) } => {
+                write!(f, "choose_n {}", player_uuid)?;
+                write_uuids(f, indices)
+            },
+            Choice { player_uuid, answer: Answer::Bool(answer) } => write!(f, "choose_bool {} {}", player_uuid, answer),
+            RandomDraw(card_uuid) => write!(f, "random_draw {}", card_uuid),
+            Action(action) => { write!(f, "action ")?; write_action(f, action) },
+        }
+    }
+}
+
+fn write_uuids<T: fmt::Display>(f: &mut fmt::Formatter, uuids: &[T]) -> fmt::Result {
+    for uuid in uuids {
+        write!(f, " {}", uuid)?;
+    }
+
+    Ok(())
+}
+
+fn write_action(f: &mut fmt::Formatter, action: &Action) -> fmt::Result {
+    match action {
+        Action::Move(delta) => write!(f, "move {} {} {:?} {} {:?} {:?}", delta.card_uuid, delta.from.player_uuid, delta.from.destination, delta.to.player_uuid, delta.to.destination, delta.reason),
+        Action::Continue { card_uuid, location, continuation } => {
+            write!(f, "continue {} {} {:?} {} {}", card_uuid, location.player_uuid, location.destination, continuation.step, continuation.card_uuids.len())?;
+            write_uuids(f, &continuation.card_uuids)?;
+            write!(f, " {}", continuation.player_uuids.len())?;
+            write_uuids(f, &continuation.player_uuids)
+        },
+        Action::DrawNumber { player_uuid, change } => { write!(f, "draw_number {} ", player_uuid)?; write_change(f, *change) },
+        Action::HandLimit { player_uuid, change } => { write!(f, "hand_limit {} ", player_uuid)?; write_change(f, *change) },
+        Action::Reveal { location, player_uuid } => write!(f, "reveal {} {:?} {}", location.player_uuid, location.destination, player_uuid),
+        Action::Shuffle => write!(f, "shuffle"),
+        Action::Fizzle { card_uuid, location } => write!(f, "fizzle {} {} {:?}", card_uuid, location.player_uuid, location.destination),
+    }
+}
+
+fn write_change(f: &mut fmt::Formatter, change: NumberChange) -> fmt::Result {
+    match change {
+        NumberChange::By(difference) => write!(f, "by {}", difference),
+        NumberChange::To(number) => write!(f, "to {}", number),
+    }
+}
+
+/// Make sure a name is a single word without newlines
+fn escape(name: &str) -> String {
+    name.replace('%', "%25").replace(' ', "%20").replace('\t', "%09").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn unescape(word: &str) -> String {
+    word.replace("%0A", "\n").replace("%0D", "\r").replace("%09", "\t").replace("%20", " ").replace("%25", "%")
+}
+
+/// Read an event from a line written by Display
+impl FromStr for Event {
+    type Err = &'static str;
+
+    fn from_str(line: &str) -> Result<Event, &'static str> {
+        let mut words = line.split(' ');
+        let words = &mut words;
+
+        let event = match words.next() {
+            Some("setup") => {
+                let spec = parse_spec(words.next())?;
+                let seed = parse(words.next())?;
+                let count: usize = parse(words.next())?;
+
+                let mut players = vec!{};
+                for _ in 0..count {
+                    let uuid = parse(words.next())?;
+                    let name = unescape(words.next().ok_or("Missing player name")?);
+                    players.push((uuid, name));
+                }
+
+                Event::Setup { spec, seed, players, cards: parse_rest(words)? }
+            },
+            Some("deal") => Event::Deal,
+            Some("seats") => Event::Seats(parse_rest(words)?),
+            Some("set_hand_limit") => Event::SetHandLimit(parse(words.next())?),
+            Some("set_unicorns_to_win") => Event::SetUnicornsToWin(parse(words.next())?),
+            Some("advance_turn") => Event::AdvanceTurn,
+            Some("step") => Event::Step,
+            Some("run_phase") => Event::RunPhase(parse_phase(words.next())?),
+            Some("play") => Event::Play { player_uuid: parse(words.next())?, card_uuid: parse(words.next())? },
+            Some("draw") => Event::Draw { player_uuid: parse(words.next())? },
+            Some("apply") => {
+                let mut actions = vec!{};
+                let mut words = words.peekable();
+
+                while words.peek().is_some() {
+                    actions.push(parse_action(&mut words)?);
+                }
+
+                return Ok(Event::Apply(actions));
+            },
+            Some("undo") => Event::Undo,
+            Some("redo") => Event::Redo,
+
+            Some("turn") => Event::Turn { turn: parse(words.next())?, player_uuid: parse(words.next())? },
+            Some("phase") => Event::Phase(parse_phase(words.next())?),
+            Some("card_played") => Event::CardPlayed { player_uuid: parse(words.next())?, card_uuid: parse(words.next())? },
+            Some("choose") => Event::Choice { player_uuid: parse(words.next())?, answer: Answer::Choose(parse(words.next())?) },
+            Some("choose_n") => {
+                let player_uuid = parse(words.next())?;
+
+                return Ok(Event::Choice { player_uuid, answer: Answer::ChooseN(parse_rest(words)?) });
+            },
+            Some("choose_bool") => Event::Choice { player_uuid: parse(words.next())?, answer: Answer::Bool(parse(words.next())?) },
+            Some("random_draw") => Event::RandomDraw(parse(words.next())?),
+            Some("action") => Event::Action(parse_action(words)?),
+
+            _ => { return Err("Unknown event"); },
+        };
+
+        match words.next() {
+            None => Ok(event),
+            Some(_) => Err("Too many words for event"),
+        }
+    }
+}
+
+fn parse<T: FromStr>(word: Option<&str>) -> Result<T, &'static str> {
+    word.ok_or("Missing word")?.parse().map_err(|_| "Invalid number")
+}
+
+fn parse_rest<T: FromStr>(words: &mut dyn Iterator<Item = &str>) -> Result<Vec<T>, &'static str> {
+    words.map(|word| parse(Some(word))).collect()
+}
+
+/// Parse a number n, followed by n words
+fn parse_n<T: FromStr>(words: &mut dyn Iterator<Item = &str>) -> Result<Vec<T>, &'static str> {
+    let n: usize = parse(words.next())?;
+
+    (0..n).map(|_| parse(words.next())).collect()
+}
+
+fn parse_spec(word: Option<&str>) -> Result<DeckSpec, &'static str> {
+    match word {
+        Some("Base") => Ok(DeckSpec::Base),
+        _ => Err("Unknown deck spec"),
+    }
+}
+
+fn parse_phase(word: Option<&str>) -> Result<Phase, &'static str> {
+    match word {
+        Some("Beginning") => Ok(Phase::Beginning),
+        Some("Draw") => Ok(Phase::Draw),
+        Some("Action") => Ok(Phase::Action),
+        Some("End") => Ok(Phase::End),
+        _ => Err("Unknown phase"),
+    }
+}
+
+fn parse_destination(word: Option<&str>) -> Result<Destination, &'static str> {
+    use Destination::*;
+
+    match word {
+        Some("Discard") => Ok(Discard),
+        Some("Drawpile") => Ok(Drawpile),
+        Some("Nursery") => Ok(Nursery),
+        Some("Hand") => Ok(Hand),
+        Some("Stable") => Ok(Stable),
+        _ => Err("Unknown destination"),
+    }
+}
+
+fn parse_reason(word: Option<&str>) -> Result<Reason, &'static str> {
+    use Reason::*;
+
+    match word {
+        Some("Play") => Ok(Play),
+        Some("Draw") => Ok(Draw),
+        Some("Discard") => Ok(Discard),
+        Some("Destroy") => Ok(Destroy),
+        Some("Sacrifice") => Ok(Sacrifice),
+        Some("Steal") => Ok(Steal),
+        Some("Return") => Ok(Return),
+        Some("Move") => Ok(Move),
+        _ => Err("Unknown reason"),
+    }
+}
+
+fn parse_location(words: &mut dyn Iterator<Item = &str>) -> Result<Location, &'static str> {
+    Ok(Location { player_uuid: parse(words.next())?, destination: parse_destination(words.next())? })
+}
+
+fn parse_change(words: &mut dyn Iterator<Item = &str>) -> Result<NumberChange, &'static str> {
+    match words.next() {
+        Some("by") => Ok(NumberChange::By(parse(words.next())?)),
+        Some("to") => Ok(NumberChange::To(parse(words.next())?)),
+        _ => Err("Unknown number change"),
+    }
+}
+
+fn parse_action(words: &mut dyn Iterator<Item = &str>) -> Result<Action, &'static str> {
+    match words.next() {
+        Some("move") => {
+            let card_uuid = parse(words.next())?;
+            let from = parse_location(words)?;
+            let to = parse_location(words)?;
+            let reason = parse_reason(words.next())?;
+
+            Ok(Action::Move(Delta { card_uuid, from, to, reason }))
+        },
+        Some("continue") => {
+            let card_uuid = parse(words.next())?;
+            let location = parse_location(words)?;
+            let step = parse(words.next())?;
+            let card_uuids = parse_n(words)?;
+            let player_uuids = parse_n(words)?;
+
+            Ok(Action::Continue { card_uuid, location, continuation: Continuation { step, card_uuids, player_uuids } })
+        },
+        Some("draw_number") => Ok(Action::DrawNumber { player_uuid: parse(words.next())?, change: parse_change(words)? }),
+        Some("hand_limit") => Ok(Action::HandLimit { player_uuid: parse(words.next())?, change: parse_change(words)? }),
+        Some("reveal") => Ok(Action::Reveal { location: parse_location(words)?, player_uuid: parse(words.next())? }),
+        Some("shuffle") => Ok(Action::Shuffle),
+        Some("fizzle") => Ok(Action::Fizzle { card_uuid: parse(words.next())?, location: parse_location(words)? }),
+        _ => Err("Unknown action"),
+    }
+}
+
+/// The append-only list of everything that happened in a game
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventLog {
+    events: Vec<Event>,
+}
+
+impl EventLog {
+    pub fn new() -> EventLog {
+        EventLog {
+            events: vec!{},
+        }
+    }
+
+    /// Add an event to the end of the log
+    pub fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Remove everything that happened since the log had the given length, eg. after a rollback
+    /// The choices that were made are kept, because a replay needs them to fail the same way again
+    pub fn roll_back(&mut self, len: usize) {
+        let mut index = 0;
+
+        self.events.retain(|event| {
+            index += 1;
+            index <= len || matches!(event, Event::Choice { .. })
+        });
+    }
+
+    /// Get all events in the order they happened
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Get the number of events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Is the log empty?
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Write all events, one event per line
+    pub fn write_to(&self, writer: &mut dyn Write) -> io::Result<()> {
+        for event in &self.events {
+            writeln!(writer, "{}", event)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read all events written by write_to
+    pub fn read_from(reader: &mut dyn BufRead) -> io::Result<EventLog> {
+        let mut log = EventLog::new();
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let event = line.parse().map_err(|reason| io::Error::new(io::ErrorKind::InvalidData, format!("Line {}: {}", number + 1, reason)))?;
+            log.push(event);
+        }
+
+        Ok(log)
+    }
+
+    /// Write the log to a file, replacing the file if it already exists
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Read the log from a file
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<EventLog> {
+        EventLog::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
@@ -1,221 +1,1131 @@
 use crate::*;
+use crate::decider::{ RecordingDecider, SharedDecider };
 
+use std::cell::RefCell;
 use std::collections::{ HashMap, VecDeque };
+use std::rc::Rc;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game {
     pub table: Table,
-    pub players: HashMap<u64, Player>,
+    pub players: HashMap<PlayerUuid, Player>,
+
+    /// The deciders that make the choices for every player
+    /// These aren't saved, so a loaded game starts with FirstChoiceDeciders until they are set again
+    #[cfg_attr(feature = "serde", serde(skip))]
+    deciders: HashMap<PlayerUuid, Box<dyn Decider>>,
+
+    /// The observers that are told about everything that happens in this game
+    /// These aren't saved either, so they have to be added again to a loaded game
+    #[cfg_attr(feature = "serde", serde(skip))]
+    observers: Vec<Box<dyn GameObserver>>,
+
+    /// The UUIDs of the players in seating order
+    seats: Vec<PlayerUuid>,
+
+    /// The index in seats of the player whose turn it is
+    current_seat: usize,
+
+    /// The number of the current turn, starting at 1
+    turn: u64,
+
+    /// The phase of the current turn
+    /// This is None as long as the turn isn't driven by step/run_turn (eg. while testing),
+    /// in which case the phases aren't enforced
+    phase: Option<Phase>,
+
+    /// Whether the current player already did their action in the Action phase
+    acted: bool,
+
+    /// Whether the current phase already ran, eg. because bot() was called before step()
+    phase_ran: bool,
+
+    /// The maximum number of cards a player may have in their hand at the end of their turn
+    hand_limit: u64,
+
+    /// The number of unicorns a player needs to win, if it's not the default
+    unicorns_to_win: Option<usize>,
+
+    /// The outcome of the game, once somebody has won
+    outcome: Option<GameOutcome>,
+
+    /// The locations that have been revealed this turn, with the player they have been revealed to
+    revealed: Vec<(Location, PlayerUuid)>,
+
+    /// All actions that have been resolved, if they are being recorded (eg. for a preview)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    resolved: Option<Vec<Action>>,
 
-    removed_player_uuid: Option<u64>,
+    /// All changes to the game, to be able to undo and redo actions
+    journal: Journal,
+
+    /// Everything that happened in this game
+    log: EventLog,
+
+    /// The generator for everything random in this game, except the shuffles of the drawpile
+    rng: GameRng,
+
+    /// Hands out the UUIDs of all cards and players of this game
+    uuids: UuidAllocator,
 }
 
 impl Game {
     pub fn new() -> Game {
+        Game::with_seed(::rand::thread_rng().gen())
+    }
+
+    /// Make a new game that is exactly the same as every other game with this seed
+    /// The same seed gives the same UUIDs, the same shuffles and the same random draws
+    pub fn with_seed(seed: u64) -> Game {
         Game {
-            table: Table::new(),
+            table: Table::with_seed(seed),
             players: HashMap::new(),
-            removed_player_uuid: None,
+            deciders: HashMap::new(),
+            observers: vec!{},
+            seats: vec! {},
+            current_seat: 0,
+            turn: 1,
+            phase: None,
+            acted: false,
+            phase_ran: false,
+            hand_limit: 7,
+            unicorns_to_win: None,
+            outcome: None,
+            revealed: vec!{},
+            resolved: None,
+            journal: Journal::default(),
+            log: EventLog::new(),
+            rng: GameRng::new(seed, 0),
+            uuids: UuidAllocator::new(),
+        }
+    }
+
+    /// Get the seed of this game
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Set up a new game with the given players and cards
+    /// Every player gets a Baby Unicorn in their stable and 5 cards in their hand
+    pub fn setup(names: &[&str], spec: DeckSpec) -> Result<Game, GameError> {
+        Game::setup_with_seed(names, spec, ::rand::thread_rng().gen())
+    }
+
+    /// Set up a new game like setup, which is exactly the same as every other game with this seed
+    pub fn setup_with_seed(names: &[&str], spec: DeckSpec, seed: u64) -> Result<Game, GameError> {
+        if !spec.player_range().contains(&names.len()) {
+            return Err(GameError::InvalidPlayerCount(names.len()));
+        }
+
+        let mut game = Game::with_players_and_cards(names.iter().map(|&name| name.to_owned()), spec, seed);
+        game.deal()?;
+
+        // The setup is where the game starts, so it can't be undone
+        game.journal = Journal::default();
+
+        Ok(game)
+    }
+
+    /// Create a game with the given players and all cards of the spec, without dealing any cards
+    fn with_players_and_cards<I: Iterator<Item = String>>(names: I, spec: DeckSpec, seed: u64) -> Game {
+        let mut game = Game::with_seed(seed);
+
+        for name in names {
+            game.new_player(name);
+        }
+
+        let babies = spec.babies(&mut game.uuids);
+        let deck = spec.deck(&mut game.uuids);
+
+        game.log.push(Event::Setup {
+            spec,
+            seed,
+            players: game.seats.iter().map(|&uuid| (uuid, game.p(uuid).name().clone())).collect(),
+            cards: babies.iter().chain(deck.iter()).map(|card| card.uuid()).collect(),
+        });
+
+        game.table.nursery.add_all(babies);
+        game.table.drawpile.add_all(deck);
+
+        game
+    }
+
+    /// Give every player a Baby Unicorn in their stable and 5 cards in their hand
+    fn deal(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::Deal, |game| {
+            // Every player starts with a baby unicorn in their stable
+            let mut actions = vec!{};
+            for player_uuid in game.player_uuids() {
+                let baby_uuids = game.table.nursery.uuids();
+                let baby_uuid = game.with_decider(player_uuid, |_game, decider| {
+                    user_choose(decider, "Which Baby Unicorn do you want to start with?", &baby_uuids)
+                })?;
+
+                game.apply_actions(vec!{ delta_same_player!(baby_uuid, player_uuid, Nursery => Stable) })?;
+
+                // And 5 random cards in their hand
+                for _ in 0..5 {
+                    actions.push(delta_same_player!(CardUuid::RANDOM, player_uuid, Drawpile => Hand));
+                }
+            }
+
+            game.apply_actions(actions)
+        })
+    }
+
+    /// Rebuild a game from its event log
+    /// The game is set up with the same players, cards and seed, and then every command is done again
+    /// with the same choices. Because of the seed, everything random happens the same way again.
+    /// This gives an error if anything happens differently than in the given log.
+    pub fn replay(log: &EventLog) -> Result<Game, GameError> {
+        let (spec, seed, players) = match log.events().first() {
+            Some(Event::Setup { spec, seed, players, .. }) => (*spec, *seed, players),
+            _ => { return Err(GameError::InvalidReplay { index: 0 }); },
+        };
+
+        let mut game = Game::with_players_and_cards(players.iter().map(|(_, name)| name.clone()), spec, seed);
+        let events = log.events();
+
+        // Make every choice the same as before
+        for player_uuid in game.player_uuids() {
+            let answers = events.iter().filter_map(|event| match event {
+                Event::Choice { player_uuid: uuid, answer } if *uuid == player_uuid => Some(answer.clone()),
+                _ => None,
+            }).collect();
+
+            game.set_decider(player_uuid, Box::new(ScriptedDecider::new(answers)));
+        }
+
+        // Do all commands again
+        // Commands that failed the first time, fail again, so their errors are ignored
+        for event in events.iter().skip(1) {
+            let _ = match event {
+                Event::Setup { .. } => Err(GameError::InvalidReplay { index: 0 }),
+                Event::Deal => game.deal(),
+                Event::Seats(seats) => { game.set_seats(seats.clone()); Ok(()) },
+                Event::SetHandLimit(hand_limit) => { game.set_hand_limit(*hand_limit); Ok(()) },
+                Event::SetUnicornsToWin(unicorns) => { game.set_unicorns_to_win(*unicorns); Ok(()) },
+                Event::AdvanceTurn => { game.advance_turn(); Ok(()) },
+                Event::Step => game.step(),
+                Event::RunPhase(Phase::Beginning) => game.bot(),
+                Event::RunPhase(Phase::Draw) => game.draw(),
+                Event::RunPhase(Phase::Action) => game.action(),
+                Event::RunPhase(Phase::End) => game.eot(),
+                Event::Play { player_uuid, card_uuid } => game.p_play(*player_uuid, *card_uuid),
+                Event::Draw { player_uuid } => game.p_draw(*player_uuid),
+                Event::Apply(actions) => game.apply_actions(actions.clone()),
+                Event::Undo => game.undo(),
+                Event::Redo => game.redo(),
+
+                // Everything else follows from the commands
+                _ => Ok(()),
+            };
+        }
+
+        // Make sure everything went exactly the same
+        if let Some(index) = (0..events.len().max(game.log.len())).find(|&i| events.get(i) != game.log.events().get(i)) {
+            return Err(GameError::InvalidReplay { index });
+        }
+
+        // From now on the players make their own choices again
+        for player_uuid in game.player_uuids() {
+            game.set_decider(player_uuid, Box::new(FirstChoiceDecider));
+        }
+
+        Ok(game)
+    }
+
+    /// Get everything that happened in this game
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+
+    /// Add a command to the log, unless it's done by another command
+    fn log_command(&mut self, event: Event) {
+        if !self.journal.is_busy() {
+            self.log.push(event);
         }
     }
 
     /// Create a new player in this game
-    pub fn new_player(&mut self, name: String) -> u64 {
-        let player = Player::new(name.clone());
+    pub fn new_player(&mut self, name: String) -> PlayerUuid {
+        let mut player = Player::new(self.uuids.player(), name.clone());
+        player.hand_limit = self.hand_limit;
         let uuid = player.uuid();
         self.players.insert(uuid, player);
+        self.deciders.insert(uuid, Box::new(FirstChoiceDecider));
+
+        // Players are seated in the order they joined
+        self.seats.push(uuid);
+
         uuid
     }
 
+    /// Get the allocator for the UUIDs of this game, eg. to make new cards
+    pub fn uuids(&mut self) -> &mut UuidAllocator {
+        &mut self.uuids
+    }
+
     /// Easy new player. Use a &str instead of a String
-    pub fn ez_new_player(&mut self, name: &str) -> u64 {
+    pub fn ez_new_player(&mut self, name: &str) -> PlayerUuid {
         self.new_player(name.to_owned())
     }
 
+    /// Let the given decider make all choices for this player
+    pub fn set_decider(&mut self, player_uuid: PlayerUuid, decider: Box<dyn Decider>) {
+        self.deciders.insert(player_uuid, decider);
+    }
+
+    /// Tell the observer about everything that happens in this game from now on
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Tell all observers about something that happened
+    fn notify<F>(&mut self, mut f: F)
+        where F: FnMut(&mut dyn GameObserver, &Game)
+    {
+        // Take the observers to give them a view of the rest of the game
+        let mut observers = ::std::mem::take(&mut self.observers);
+
+        for observer in observers.iter_mut() {
+            f(&mut **observer, self);
+        }
+
+        self.observers = observers;
+    }
+
+    /// Tell all observers which actions the card triggered, if it triggered anything
+    fn notify_trigger(&mut self, card_uuid: CardUuid, actions: ResActions) -> ResActions {
+        if let Ok(actions) = &actions {
+            if !actions.is_empty() {
+                self.notify(|observer, game| observer.on_trigger(game, card_uuid, actions));
+            }
+        }
+
+        actions
+    }
+
+    /// Temporarily take the decider of the player to pass it to a card or player
+    /// You MUST call readd_decider after this
+    /// All choices the decider makes are added to the log
+    fn process_decider(&mut self, player_uuid: PlayerUuid) -> RecordingDecider {
+        // Players without a decider just go for the first choice
+        RecordingDecider {
+            inner: self.deciders.remove(&player_uuid).unwrap_or_else(|| Box::new(FirstChoiceDecider)),
+            answers: vec!{},
+        }
+    }
+
+    /// Give the decider back to the player after processing
+    fn readd_decider(&mut self, player_uuid: PlayerUuid, decider: RecordingDecider) {
+        for answer in decider.answers {
+            self.log.push(Event::Choice { player_uuid, answer });
+        }
+
+        self.deciders.insert(player_uuid, decider.inner);
+    }
+
+    /// Call the function with the decider of the given player
+    fn with_decider<R, F>(&mut self, player_uuid: PlayerUuid, f: F) -> R
+        where F: FnOnce(&mut Game, &mut dyn Decider) -> R
+    {
+        let mut decider = self.process_decider(player_uuid);
+        let actions = f(self, &mut decider);
+        self.readd_decider(player_uuid, decider);
+        actions
+    }
+
+    /// Randomly draw new seats for all players
+    /// The first player of the new seating order gets the current turn
+    pub fn shuffle_seats(&mut self) {
+        let mut seats = self.seats.clone();
+        seats.shuffle(&mut self.rng);
+        self.set_seats(seats);
+    }
+
+    /// Seat the players in the given order
+    /// The first player gets the current turn
+    fn set_seats(&mut self, seats: Vec<PlayerUuid>) {
+        self.log_command(Event::Seats(seats.clone()));
+        self.seats = seats;
+        self.current_seat = 0;
+    }
+
+    /// Get the UUIDs of the players in seating order
+    pub fn seats(&self) -> &[PlayerUuid] {
+        &self.seats
+    }
+
+    /// Get the number of the current turn
+    pub fn turn(&self) -> u64 {
+        self.turn
+    }
+
     /// Get the UUID of the current player
-    fn current_player_uuid(&self) -> Option<u64> {
-        // @TODO Get current player instead of choosing a player at random
-        let mut names = self.players.values().map(|player| (player.name(), player.uuid())).collect::<Vec<(&String, u64)>>();
-        names.sort_unstable();
-        names.get(0).map(|(_, uuid)| *uuid)
+    pub fn current_player_uuid(&self) -> Option<PlayerUuid> {
+        self.seats.get(self.current_seat).copied()
+    }
+
+    /// Get the player whose turn it is
+    pub fn current_player(&self) -> Option<&Player> {
+        self.current_player_uuid().map(|player_uuid| self.p(player_uuid))
+    }
+
+    /// Get the number of unicorns a player needs in their stable to win
+    /// By default this is 7, or 6 if there are 6 or more players
+    pub fn unicorns_to_win(&self) -> usize {
+        match self.unicorns_to_win {
+            Some(unicorns) => unicorns,
+            None if self.seats.len() >= 6 => 6,
+            None => 7,
+        }
+    }
+
+    /// Change the number of unicorns a player needs to win
+    pub fn set_unicorns_to_win(&mut self, unicorns: usize) {
+        self.log_command(Event::SetUnicornsToWin(unicorns));
+        self.unicorns_to_win = Some(unicorns);
+    }
+
+    /// Get the maximum number of cards a player may have in their hand at the end of their turn
+    /// Keep in mind that cards can change this for every player
+    pub fn hand_limit(&self) -> u64 {
+        self.hand_limit
+    }
+
+    /// Change the maximum number of cards a player may have in their hand at the end of their turn
+    /// This also resets the hand limit of every player
+    pub fn set_hand_limit(&mut self, hand_limit: u64) {
+        self.log_command(Event::SetHandLimit(hand_limit));
+        self.hand_limit = hand_limit;
+
+        for player in self.players.values_mut() {
+            player.hand_limit = hand_limit;
+        }
+    }
+
+    /// Get the outcome of the game, or None if nobody has won yet
+    pub fn outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Is the game over?
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Make sure the game isn't over yet
+    fn check_not_over(&self) -> Result<(), GameError> {
+        if self.is_over() {
+            Err(GameError::GameOver)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check if somebody has won and end the game if so
+    /// If several players have enough unicorns, the current player goes first and then the
+    /// players after them in seating order.
+    fn check_winner(&mut self) {
+        if self.is_over() || self.seats.is_empty() {
+            return;
+        }
+
+        let unicorns_to_win = self.unicorns_to_win();
+
+        let winner = (0..self.seats.len())
+            .map(|i| self.seats[(self.current_seat + i) % self.seats.len()])
+            .find(|&player_uuid| self.p(player_uuid).stable().count_unicorns() >= unicorns_to_win);
+
+        if let Some(winner) = winner {
+            let outcome = GameOutcome {
+                winner,
+                turn: self.turn,
+                stables: self.seats.iter().map(|&player_uuid| (player_uuid, self.p(player_uuid).stable().ids())).collect(),
+            };
+
+            self.notify(|observer, game| observer.on_win(game, &outcome));
+            self.outcome = Some(outcome);
+        }
+    }
+
+    /// Get the phase of the current turn
+    /// This is None when the turn isn't driven by step or run_turn
+    pub fn phase(&self) -> Option<Phase> {
+        self.phase
+    }
+
+    /// Make sure the game is in the expected phase
+    /// If the turn isn't driven by step or run_turn, every phase is allowed
+    fn check_phase(&self, expected: Phase) -> Result<(), GameError> {
+        match self.phase {
+            Some(actual) if actual != expected => Err(GameError::WrongPhase { expected, actual }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Make sure the game is in the expected phase and the phase didn't run yet
+    /// If the turn isn't driven by step or run_turn, phases may run as often as needed
+    fn check_run_phase(&self, expected: Phase) -> Result<(), GameError> {
+        self.check_not_over()?;
+        self.check_phase(expected)?;
+
+        if self.phase.is_some() && self.phase_ran {
+            return Err(GameError::NotAllowed { reason: "Phase already ran this turn" });
+        }
+
+        Ok(())
+    }
+
+    /// Run the current phase of the turn and go to the next phase
+    /// After the End of Turn phase, the turn goes to the next player
+    pub fn step(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::Step, |game| {
+            use Phase::*;
+
+            game.check_not_over()?;
+
+            let phase = *game.phase.get_or_insert(Beginning);
+
+            if phase == Beginning {
+                if let Some(player_uuid) = game.current_player_uuid() {
+                    game.log.push(Event::Turn { turn: game.turn, player_uuid });
+
+                    let turn = game.turn;
+                    game.notify(|observer, game| observer.on_turn_start(game, turn, player_uuid));
+                }
+            }
+
+            game.log.push(Event::Phase(phase));
+            game.notify(|observer, game| observer.on_phase(game, phase));
+
+            match phase {
+                // The phase may have already been run by itself
+                Beginning => {
+                    game.acted = false;
+
+                    if !game.phase_ran {
+                        game.bot()?;
+                    }
+                },
+
+                Draw => {
+                    if !game.phase_ran {
+                        game.draw()?;
+                    }
+                },
+
+                Action => {
+                    // The player may have already played a card or drawn one by themselves
+                    if !game.acted {
+                        game.action()?;
+                    }
+                },
+
+                End => {
+                    if !game.phase_ran {
+                        game.eot()?;
+                    }
+
+                    if let Some(player_uuid) = game.current_player_uuid() {
+                        let turn = game.turn;
+                        game.notify(|observer, game| observer.on_turn_end(game, turn, player_uuid));
+                    }
+                },
+            }
+
+            // Don't go to the next phase when the game is over
+            if game.is_over() {
+                return Ok(());
+            }
+
+            if phase == End {
+                game.advance_turn();
+            }
+
+            game.phase = Some(phase.next());
+            game.phase_ran = false;
+
+            Ok(())
+        })
+    }
+
+    /// Run all (remaining) phases of the current turn
+    /// This stops early when somebody wins during the turn
+    pub fn run_turn(&mut self) -> Result<(), GameError> {
+        let turn = self.turn;
+
+        loop {
+            self.step()?;
+
+            if self.turn != turn || self.is_over() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Give the turn to the player in the next seat
+    pub fn advance_turn(&mut self) {
+        self.log_command(Event::AdvanceTurn);
+
+        if !self.seats.is_empty() {
+            self.current_seat = (self.current_seat + 1) % self.seats.len();
+        }
+
+        self.turn += 1;
+
+        // Revealed cards are only visible during the turn they were revealed in
+        self.revealed.clear();
     }
 
     /// Apply all actions in the actions vector
     /// (this is a wrapper before apply_actions_deque to turn the vec into a vecdeque)
-    pub fn apply_actions(&mut self, actions: Vec<Delta>) -> Result<(), &'static str> {
+    pub fn apply_actions(&mut self, actions: Vec<Action>) -> Result<(), GameError> {
         self.apply_actions_deque(VecDeque::from(actions))
     }
 
     /// Get a cardlist by destination
-    fn list_by_location(&mut self, location: &Location) -> &mut dyn CardList {
+    fn list_by_location(&mut self, location: &Location) -> Result<&mut dyn CardList, GameError> {
         match location.destination {
             // Table
-            Destination::Nursery => &mut self.table.nursery as &mut dyn CardList,
-            Destination::Drawpile => &mut self.table.drawpile as &mut dyn CardList,
-            Destination::Discard => &mut self.table.discard as &mut dyn CardList,
+            Destination::Nursery => Ok(&mut self.table.nursery as &mut dyn CardList),
+            Destination::Drawpile => Ok(&mut self.table.drawpile as &mut dyn CardList),
+            Destination::Discard => Ok(&mut self.table.discard as &mut dyn CardList),
 
             // Player
-            Destination::Hand => self.p_mut(location.player_uuid).hand_mut() as &mut dyn CardList,
-            Destination::Stable => self.p_mut(location.player_uuid).stable_mut() as &mut dyn CardList,
+            Destination::Hand => Ok(self.player_mut(location.player_uuid)?.hand_mut() as &mut dyn CardList),
+            Destination::Stable => Ok(self.player_mut(location.player_uuid)?.stable_mut() as &mut dyn CardList),
+        }
+    }
+
+    /// Get the list of cards at the given location without changing it
+    fn list_at(&self, location: &Location) -> Result<&dyn CardList, GameError> {
+        match location.destination {
+            // Table
+            Destination::Nursery => Ok(&self.table.nursery as &dyn CardList),
+            Destination::Drawpile => Ok(&self.table.drawpile as &dyn CardList),
+            Destination::Discard => Ok(&self.table.discard as &dyn CardList),
 
-            // Special
-            Destination::Stage | Destination::UpdateDrawNumber => unimplemented!("Invalid destination Stage"),
+            // Player
+            Destination::Hand => Ok(self.player(location.player_uuid)?.hand() as &dyn CardList),
+            Destination::Stable => Ok(self.player(location.player_uuid)?.stable() as &dyn CardList),
         }
     }
 
-    /// Get the actions corresponding to the stage we jumped to
-    fn jump_to_stage(&mut self, action: &Delta) -> ResDeltas {
-        // Take the respective card
-        match self.list_by_location(&action.from).process_card(action.card_uuid) {
-            None => Err("game#apply_special_action: card_uuid not in from"),
+    /// Check if a card may move between these two locations
+    fn is_valid_move(from: Destination, to: Destination) -> bool {
+        use Destination::*;
+
+        match (from, to) {
+            // Discarded from other locations shouldn't be possible
+            (Discard, Discard) | (Drawpile, Discard) | (Nursery, Discard) => false,
 
-            Some(mut card) => {
-                // Get the actions that happen at this stage
-                // Keep in mind that we stored the stage in action.to.player_uuid
-                let actions = card.on_play_stage(self.p(action.from.player_uuid), self, action.to.player_uuid);
+            (_, Discard) | (_, Drawpile) | (_, Nursery) | (_, Hand) | (_, Stable) => true,
+        }
+    }
 
-                // Put the card back in the list
-                self.list_by_location(&action.from).readd_card(card);
+    /// Would this delta destroy a card that may not be destroyed, eg. a Unicorn next to a Rainbow Aura?
+    fn is_protected(&self, action: &Delta) -> bool {
+        if action.reason != Reason::Destroy || action.from.destination != Destination::Stable {
+            return false;
+        }
 
-                actions
-            },
+        // Unknown players and cards are reported when the card is taken
+        match self.player(action.from.player_uuid).map(|player| player.stable()) {
+            Ok(stable) => stable.get_card(action.card_uuid).is_some_and(|card| !stable.other_may_destroy(&**card)),
+            Err(_) => false,
         }
     }
 
-    fn leave_stable(&mut self, action: &Delta, card: &mut Box<dyn Card>) -> ResDeltas {
-        match card.on_leave_stable(self.p(action.from.player_uuid), self) {
-            Err(err) => Err(err),
+    /// Get the actions of the follow-up step of the card's effect
+    fn continue_effect(&mut self, card_uuid: CardUuid, location: &Location, continuation: &Continuation) -> ResActions {
+        self.with_card(location, card_uuid, |card, player, game, decider| card.on_continue(player, game, decider, continuation))
+    }
 
-            Ok(mut actions) => {
+    /// Call a hook of the card at the given location
+    /// The hook gets a read-only view of the game, in which the card and its player are where they belong,
+    /// and its own copy of the card to change, which replaces the card in the game afterwards
+    fn with_card<F>(&mut self, location: &Location, card_uuid: CardUuid, hook: F) -> ResActions
+        where F: FnOnce(&mut dyn Card, &Player, &Game, &mut dyn Decider) -> ResActions
+    {
+        let before = self.list_at(location)?.get_card(card_uuid)
+            .ok_or_else(|| GameError::UnknownCard { uuid: card_uuid, location: location.clone() })?
+            .clone();
+        let mut card = before.clone();
+
+        let player_uuid = location.player_uuid;
+        let actions = self.with_decider(player_uuid, |game, decider| {
+            hook(&mut *card, game.player(player_uuid)?, game, decider)
+        });
+        let actions = self.notify_trigger(card_uuid, actions);
+
+        self.record_card_state(location, before, &*card);
+
+        // Nothing could move while the hook ran, so the card is still at the same place
+        if let Some(slot) = self.list_by_location(location)?.get_card_mut(card_uuid) {
+            *slot = card;
+        }
 
-                // Temporarily remove player from game to allow to take cards from stable
-                match self.process_player(action.from.player_uuid) {
-                    None => Err("game#leave_stable: Unknown player in from"),
+        actions
+    }
 
-                    Some(mut from_player) => {
-                        // Prepare to loop over stable
-                        from_player.stable_mut().reset_cards();
+    /// Call a hook of the card that is moving, and so isn't in any list right now
+    fn with_moving_card<F>(&mut self, card: &mut Box<dyn Card>, player_uuid: PlayerUuid, hook: F) -> ResActions
+        where F: FnOnce(&mut dyn Card, &Player, &Game, &mut dyn Decider) -> ResActions
+    {
+        let actions = self.with_decider(player_uuid, |game, decider| {
+            hook(&mut **card, game.player(player_uuid)?, game, decider)
+        });
 
-                        while let Some(mut c) = from_player.stable_mut().next_card() {
+        self.notify_trigger(card.uuid(), actions)
+    }
 
-                            // Call stable_update_leave on all cards in this stable
-                            // @TODO This could possibly depend on the actions from card.on_leave_stable
-                            let more_actions = c.stable_update_leave(&**card, &from_player, self);
+    /// Call a hook of every card in the player's stable, giving the actions each card triggers
+    fn stable_triggers<F>(&mut self, player_uuid: PlayerUuid, mut hook: F) -> Result<Vec<(CardUuid, Vec<Action>)>, GameError>
+        where F: FnMut(&mut dyn Card, &Player, &Game, &mut dyn Decider) -> ResActions
+    {
+        let location = Location { player_uuid, destination: Destination::Stable };
+        let mut triggers = vec!{};
 
-                            // Add the card back after processing
-                            from_player.stable_mut().readd_card(c);
+        for card_uuid in self.player(player_uuid)?.stable().uuids() {
+            let actions = self.with_card(&location, card_uuid, &mut hook)?;
+            triggers.push((card_uuid, actions));
+        }
 
-                            if more_actions.is_err() {
-                                return more_actions;
-                            }
+        Ok(triggers)
+    }
 
-                            actions.append(&mut more_actions.unwrap());  // CONFIRMED SAFE UNWRAP
-                        }
+    /// Resolve an action that doesn't move a card, giving the actions that follow from it
+    fn resolve_effect(&mut self, action: Action) -> ResActions {
+        self.record(&action);
 
-                        self.readd_player(from_player);
+        match action {
+            Action::Move(_) => unreachable!("Moves are resolved by resolve_actions_deque"),
 
-                        Ok(actions)
-                    }
+            Action::Continue { card_uuid, location, continuation } => self.continue_effect(card_uuid, &location, &continuation),
+
+            Action::DrawNumber { player_uuid, change } => {
+                let player = self.player_mut(player_uuid)?;
+                let (may_draw_number, hand_limit) = (player.may_draw_number, player.hand_limit);
+                player.may_draw_number = change.apply(may_draw_number);
+
+                self.record_limits(player_uuid, may_draw_number, hand_limit);
+                delta_nothing!()
+            },
+
+            Action::HandLimit { player_uuid, change } => {
+                let player = self.player_mut(player_uuid)?;
+                let (may_draw_number, hand_limit) = (player.may_draw_number, player.hand_limit);
+                player.hand_limit = change.apply(hand_limit);
+
+                self.record_limits(player_uuid, may_draw_number, hand_limit);
+                delta_nothing!()
+            },
+
+            Action::Shuffle => {
+                let before = self.table.drawpile.uuids();
+                self.table.drawpile.shuffle();
+                let after = self.table.drawpile.uuids();
+
+                self.journal.record(Change::Shuffle { before, after });
+                delta_nothing!()
+            },
+
+            // The player may see the location until the end of the turn
+            Action::Reveal { location, player_uuid } => {
+                if !self.is_revealed(&location, player_uuid) {
+                    self.revealed.push((location, player_uuid));
                 }
+
+                delta_nothing!()
             },
+
+            // Fizzles only have to be remembered
+            Action::Fizzle { .. } => delta_nothing!(),
         }
     }
 
-    fn enter_stable(&mut self, action: &Delta, card: &mut Box<dyn Card>) -> ResDeltas {
-        match card.on_enter_stable(action.from.destination, self.p(action.to.player_uuid), self) {
-            Err(err) => Err(err),
+    fn leave_stable(&mut self, action: &Delta, card: &mut Box<dyn Card>) -> ResActions {
+        let player_uuid = action.from.player_uuid;
 
-            Ok(mut actions) => {
-                // Temporarily remove player from game to allow to take cards from stable
-                match self.process_player(action.to.player_uuid) {
-                    None => Err("game#enter_stable: Unknown player in to"),
+        let actions = self.with_moving_card(card, player_uuid, |card, player, game, decider| card.on_leave_stable(player, game, decider))?;
 
-                    Some(mut to_player) => {
-                        // Reset cards in order to loop over them
-                        to_player.stable_mut().reset_cards();
+        // The card itself and the cards in the stable trigger at the same moment
+        let mut triggers = vec!{ (card.uuid(), actions) };
 
-                        // Loop over all cards in the player's stable
-                        while let Some(mut c) = to_player.stable_mut().next_card() {
+        // Call stable_update_leave on all cards in this stable
+        // @TODO This could possibly depend on the actions from card.on_leave_stable
+        triggers.append(&mut self.stable_triggers(player_uuid, |c, player, game, decider| c.stable_update_leave(&**card, player, game, decider))?);
 
-                            // Get the stable_update_enter actions
-                            // @TODO This could possibly depend on the actions from card.on_enter_stable
-                            let more_actions = c.stable_update_enter(&**card, &to_player, self);
+        // The player chooses the order in which the triggers resolve
+        self.with_decider(player_uuid, |_game, decider| user_order_triggers(decider, triggers))
+    }
 
-                            // Add the card back after processing
-                            to_player.stable_mut().readd_card(c);
+    fn enter_stable(&mut self, action: &Delta, card: &mut Box<dyn Card>) -> ResActions {
+        let player_uuid = action.to.player_uuid;
 
-                            if more_actions.is_err() {
-                                return more_actions;
-                            }
+        let actions = self.with_moving_card(card, player_uuid, |card, player, game, decider| card.on_enter_stable(action.from.destination, player, game, decider))?;
 
-                            actions.append(&mut more_actions.unwrap());  // CONFIRMED SAFE UNWRAP
-                        }
+        // The card itself and the cards in the stable trigger at the same moment
+        let mut triggers = vec!{ (card.uuid(), actions) };
 
-                        self.readd_player(to_player);
+        // Call stable_update_enter on all cards in this stable
+        // @TODO This could possibly depend on the actions from card.on_enter_stable
+        triggers.append(&mut self.stable_triggers(player_uuid, |c, player, game, decider| c.stable_update_enter(&**card, player, game, decider))?);
 
-                        Ok(actions)
-                    },
+        // The player chooses the order in which the triggers resolve
+        self.with_decider(player_uuid, |_game, decider| user_order_triggers(decider, triggers))
+    }
+
+    /// Apply all actions in the actions vecdeque
+    /// This is all-or-nothing: if any action (or any action following from it) fails,
+    /// the game is restored to the state before this call and the error is returned
+    pub fn apply_actions_deque(&mut self, actions: VecDeque<Action>) -> Result<(), GameError> {
+        let given = actions.len();
+        self.apply_actions_given(actions, given)
+    }
+
+    /// Apply the actions that cards triggered, eg. at the beginning of a turn
+    /// Like everything they trigger while resolving, these fizzle when their card has left in the meantime
+    fn apply_triggered(&mut self, actions: Vec<Action>) -> Result<(), GameError> {
+        self.apply_actions_given(VecDeque::from(actions), 0)
+    }
+
+    /// Apply the actions, of which the last `given` ones were given by the caller instead of triggered by cards
+    fn apply_actions_given(&mut self, actions: VecDeque<Action>, given: usize) -> Result<(), GameError> {
+        self.check_not_over()?;
+
+        self.journaled(Event::Apply(actions.iter().cloned().collect()), |game| {
+            game.all_or_nothing(|game| game.resolve_actions_deque(actions, given))
+        })
+    }
+
+    /// Run the function and roll back everything it changed to the cards and players if it fails
+    /// This should run inside the journaled action, so observers see the game as it is after the error
+    fn all_or_nothing<R, F>(&mut self, f: F) -> Result<R, GameError>
+        where F: FnOnce(&mut Game) -> Result<R, GameError>
+    {
+        // Remember the state of all cards and players to be able to roll back
+        let table = self.table.clone();
+        let players = self.players.clone();
+        let revealed = self.revealed.clone();
+        let resolved_len = self.resolved.as_ref().map(|resolved| resolved.len());
+        let journal_len = self.journal.pending_len();
+        let log_len = self.log.len();
+
+        let result = f(self);
+
+        if result.is_err() {
+            self.table = table;
+            self.players = players;
+            self.revealed = revealed;
+            self.journal.truncate(journal_len);
+            self.log.roll_back(log_len);
+
+            if let (Some(resolved), Some(resolved_len)) = (self.resolved.as_mut(), resolved_len) {
+                resolved.truncate(resolved_len);
+            }
+        }
+
+        result
+    }
+
+    /// Get everything that keeps track of how far the game is
+    fn turn_state(&self) -> TurnState {
+        TurnState {
+            current_seat: self.current_seat,
+            turn: self.turn,
+            phase: self.phase,
+            acted: self.acted,
+            phase_ran: self.phase_ran,
+            outcome: self.outcome.clone(),
+            revealed: self.revealed.clone(),
+        }
+    }
+
+    /// Go back (or forward) to the given turn state
+    fn set_turn_state(&mut self, turn_state: TurnState) {
+        self.current_seat = turn_state.current_seat;
+        self.turn = turn_state.turn;
+        self.phase = turn_state.phase;
+        self.acted = turn_state.acted;
+        self.phase_ran = turn_state.phase_ran;
+        self.outcome = turn_state.outcome;
+        self.revealed = turn_state.revealed;
+    }
+
+    /// Run the function as a single action, so it can be undone as a whole
+    /// Actions inside other actions (eg. p_play inside step) are part of the outermost action,
+    /// so only the command of the outermost action is added to the log
+    fn journaled<R, F>(&mut self, command: Event, f: F) -> Result<R, GameError>
+        where F: FnOnce(&mut Game) -> Result<R, GameError>
+    {
+        self.log_command(command);
+
+        if self.journal.begin() {
+            let turn_state = self.turn_state();
+            self.journal.set_turn_state(turn_state);
+        }
+
+        let result = f(self);
+
+        if self.journal.end() {
+            if let Some(before) = self.journal.take_turn_state() {
+                let after = self.turn_state();
+
+                if before != after {
+                    self.journal.record(Change::Turn { before, after });
                 }
+            }
+
+            // Even failed actions can have changed something that needs to be undone
+            self.journal.commit();
+
+            if let Err(err) = &result {
+                self.notify(|observer, game| observer.on_error(game, err));
+            }
+        }
+
+        result
+    }
+
+    /// Record the changes of the draw number and hand limit of the player since the given values
+    fn record_limits(&mut self, player_uuid: PlayerUuid, may_draw_number: u64, hand_limit: u64) {
+        let player = self.p(player_uuid);
+        let (after_draw_number, after_hand_limit) = (player.may_draw_number, player.hand_limit);
+
+        if may_draw_number != after_draw_number {
+            self.journal.record(Change::DrawNumber { player_uuid, before: may_draw_number, after: after_draw_number });
+        }
+
+        if hand_limit != after_hand_limit {
+            self.journal.record(Change::HandLimit { player_uuid, before: hand_limit, after: after_hand_limit });
+        }
+    }
+
+    /// Record the change of a card that stays at the same location, if anything changed
+    fn record_card_state(&mut self, location: &Location, before: Box<dyn Card>, after: &dyn Card) {
+        // Everything a card remembers is in its state
+        if before.state() != after.state() {
+            self.journal.record(Change::CardState { location: location.clone(), before, after: after.clone_box() });
+        }
+    }
+
+    /// Can the last action be undone?
+    pub fn can_undo(&self) -> bool {
+        self.journal.can_undo()
+    }
+
+    /// Can the last undone action be redone?
+    pub fn can_redo(&self) -> bool {
+        self.journal.can_redo()
+    }
+
+    /// Undo the last action, eg. playing a card or a single phase of the turn
+    pub fn undo(&mut self) -> Result<(), GameError> {
+        self.log_command(Event::Undo);
+
+        let changes = self.journal.take_undo().ok_or(GameError::NothingToUndo)?;
+
+        for change in changes.iter().rev() {
+            self.undo_change(change)?;
+        }
+
+        self.journal.push_redo(changes);
+
+        Ok(())
+    }
+
+    /// Redo the last undone action
+    /// This repeats exactly what happened, so no choices are made again
+    pub fn redo(&mut self) -> Result<(), GameError> {
+        self.log_command(Event::Redo);
+
+        let changes = self.journal.take_redo().ok_or(GameError::NothingToRedo)?;
+
+        for change in &changes {
+            self.redo_change(change)?;
+        }
+
+        self.journal.push_undo(changes);
+
+        Ok(())
+    }
+
+    /// Put the card at the location back in the given state
+    fn replace_card(&mut self, location: &Location, card: &dyn Card) -> Result<(), GameError> {
+        let uuid = card.uuid();
+
+        match self.list_by_location(location)?.get_card_mut(uuid) {
+            Some(old) => { *old = card.clone_box(); Ok(()) },
+            None => Err(GameError::UnknownCard { uuid, location: location.clone() }),
+        }
+    }
+
+    fn undo_change(&mut self, change: &Change) -> Result<(), GameError> {
+        match change {
+            Change::Move { delta, before, index, from_order, to_order, .. } => {
+                let to = self.list_by_location(&delta.to)?;
+                to.try_take_card(delta.card_uuid, &delta.to)?;
+                to.reorder(&to_order.before);
+
+                // Put the card back where it was, so it's drawn or chosen the same way again
+                let from = self.list_by_location(&delta.from)?;
+                let index = (*index).min(from.len());
+                from.__list_mut().insert(index, before.clone());
+                from.reorder(&from_order.before);
             },
+
+            Change::DrawNumber { player_uuid, before, .. } => self.player_mut(*player_uuid)?.may_draw_number = *before,
+            Change::HandLimit { player_uuid, before, .. } => self.player_mut(*player_uuid)?.hand_limit = *before,
+            Change::Shuffle { before, .. } => self.table.drawpile.reorder(before),
+            Change::CardState { location, before, .. } => self.replace_card(location, &**before)?,
+            Change::Turn { before, .. } => self.set_turn_state(before.clone()),
         }
+
+        Ok(())
     }
 
-    /// Apply all actions in the actions vecdeque
+    fn redo_change(&mut self, change: &Change) -> Result<(), GameError> {
+        match change {
+            Change::Move { delta, after, from_order, to_order, .. } => {
+                let from = self.list_by_location(&delta.from)?;
+                from.try_take_card(delta.card_uuid, &delta.from)?;
+                from.reorder(&from_order.after);
+
+                // Don't use add_card, because the drawpile would shuffle again
+                let to = self.list_by_location(&delta.to)?;
+                to.__list_mut().push(after.clone());
+                to.reorder(&to_order.after);
+            },
+
+            Change::DrawNumber { player_uuid, after, .. } => self.player_mut(*player_uuid)?.may_draw_number = *after,
+            Change::HandLimit { player_uuid, after, .. } => self.player_mut(*player_uuid)?.hand_limit = *after,
+            Change::Shuffle { after, .. } => self.table.drawpile.reorder(after),
+            Change::CardState { location, after, .. } => self.replace_card(location, &**after)?,
+            Change::Turn { after, .. } => self.set_turn_state(after.clone()),
+        }
+
+        Ok(())
+    }
+
+    /// Remember that this action has been resolved, in the log and if the actions are being recorded
+    fn record(&mut self, action: &Action) {
+        self.log.push(Event::Action(action.clone()));
+
+        if let Some(resolved) = self.resolved.as_mut() {
+            resolved.push(action.clone());
+        }
+
+        match action {
+            Action::Move(delta) => self.notify(|observer, game| observer.on_card_moved(game, delta)),
+            Action::Fizzle { card_uuid, location } => self.notify(|observer, game| observer.on_fizzle(game, *card_uuid, location)),
+            _ => {},
+        }
+    }
+
+    /// Resolve all actions in the actions vecdeque, stopping at the first error
+    /// This leaves the game half-changed on errors, so use apply_actions_deque instead
     /// @TODO Split up in smaller helper functions because this is a madness
-    pub fn apply_actions_deque(&mut self, mut actions: VecDeque<Delta>) -> Result<(), &'static str> {
-        println!("\n\n################");
+    fn resolve_actions_deque(&mut self, mut actions: VecDeque<Action>, given: usize) -> Result<(), GameError> {
+        // Actions triggered by the action that is being resolved
+        let mut triggered: Vec<Action> = vec!{};
+
+        // The number of given actions that haven't been resolved yet
+        // Triggered actions are always resolved before these, so these are always at the back of the queue
+        let mut given_left = given;
+
+        loop {
+            // Resolve depth-first: everything an action triggers is resolved
+            // before the actions that were already waiting in the queue
+            for triggered_action in triggered.drain(..).rev() {
+                actions.push_front(triggered_action);
+            }
+
+            let action = match actions.pop_front() {
+                Some(action) => action,
+                None => break,
+            };
 
-        while let Some(action) = actions.pop_front() {
-            // Debugging
-            println!("### apply action");
-            println!(" +    self: {:?}\n", self);
-            println!(" +    action: {:?}\n", action);
+            let is_triggered = actions.len() >= given_left;
+            if !is_triggered {
+                given_left -= 1;
+            }
 
             /// Shortcut to add more actions or return on errors
             macro_rules! add_more_actions {
                 ($more:expr) => {
-                    let more_actions = $more;
-
-                    if let Err(err) = more_actions {
-                        return Err(err);
-                    }
-
-                    let more_actions = more_actions.unwrap();  // CONFIRMED SAFE UNWRAP
+                    let mut more_actions = $more?;
 
-                    // @TODO: Add front or back?
-                    actions.append(&mut VecDeque::from(more_actions));
+                    triggered.append(&mut more_actions);
                 };
             }
 
-            // Handle special stage change action
-            if action.to.destination == Destination::Stage {
-                add_more_actions!(self.jump_to_stage(&action));
+            // Everything that doesn't move a card is handled separately
+            let action = match action {
+                Action::Move(delta) => delta,
 
-                // Don't do anything from the normal flow
-                continue;
+                other => {
+                    add_more_actions!(self.resolve_effect(other));
+                    continue;
+                },
+            };
+
+            // Make sure the card may move like this before touching anything
+            if !Game::is_valid_move(action.from.destination, action.to.destination) {
+                return Err(GameError::InvalidDelta(action));
             }
 
-            // Handle special update draw number action
-            if action.to.destination == Destination::UpdateDrawNumber {
-                let draw_number = action.to.player_uuid;
+            // Triggered effects are chosen when they trigger, so their card may have left since
+            // (eg. when two triggers sacrifice the same card or draw the last card), which lets the effect fizzle
+            let random = action.card_uuid == CardUuid::RANDOM && action.from.destination == Destination::Drawpile;
+            let gone = if random {
+                self.table.drawpile.is_empty()
+            } else {
+                self.list_at(&action.from)?.get_card(action.card_uuid).is_none()
+            };
 
-                if draw_number == 0 {
-                    self.p_mut(action.from.player_uuid).may_draw_number = 0;
-                } else {
-                    self.p_mut(action.from.player_uuid).may_draw_number += draw_number;
-                }
+            if is_triggered && gone {
+                self.record(&Action::Fizzle { card_uuid: action.card_uuid, location: action.from });
+                continue;
+            }
 
-                // Don't do anything from the normal flow
+            // Protected cards can't be destroyed, so the move fizzles
+            if self.is_protected(&action) {
+                self.record(&Action::Fizzle { card_uuid: action.card_uuid, location: action.from });
                 continue;
             }
 
-            // Take the card from "from"
-            match self.list_by_location(&action.from).take_card(action.card_uuid) {
-                None => { return Err("game#apply_actions: Invalid delta: card_uuid not in from location"); },
+            // Make sure the card can be put somewhere before taking it
+            self.list_by_location(&action.to)?;
+
+            // Take the card from "from", remembering where it was to be able to put it back there
+            let from_before = self.list_by_location(&action.from)?.uuids();
+            match self.list_by_location(&action.from)?.try_take_card(action.card_uuid, &action.from) {
+                Err(err) => { return Err(err); },
 
-                Some(mut card) => {
+                Ok(mut card) => {
                     use Destination::*;
 
+                    let index = from_before.iter().position(|&uuid| uuid == card.uuid()).unwrap_or_default();
+                    let from_order = Order { before: from_before, after: self.list_by_location(&action.from)?.uuids() };
+
+                    if random {
+                        self.log.push(Event::RandomDraw(card.uuid()));
+                    }
+
+                    // Record which card actually moved, since random draws don't say that
+                    let resolved = Delta { card_uuid: card.uuid(), ..action.clone() };
+                    self.record(&Action::Move(resolved.clone()));
+
+                    // Remember the state of the card before anything happens to it
+                    let before = card.clone();
+
                     // Add on_leave_stable and stable_update_leave for old stable
                     if action.from.destination == Stable { add_more_actions!(self.leave_stable(&action, &mut card)); }
 
@@ -229,14 +1139,34 @@ impl Game {
 
                         // --- stuff that needs more actions ---
 
-                        // If the card was discarded
-                        (Hand, Discard) => { add_more_actions!(card.on_discard(action.to.destination, self.p(action.from.player_uuid), self)); },
+                        // If the card was discarded, and not just put on the discard pile after being played
+                        (Hand, Discard) => {
+                            let player_uuid = action.from.player_uuid;
+
+                            if action.reason == Reason::Discard {
+                                add_more_actions!(self.with_moving_card(&mut card, player_uuid, |card, player, game, decider| card.on_discard(action.to.destination, player, game, decider)));
+                            }
+                        },
 
                         // If the card was destroyed or sacrificed
-                        (Stable, Discard) => { add_more_actions!(card.on_destroy(action.to.destination, self.p(action.from.player_uuid), self)); },
+                        (Stable, Discard) => {
+                            let player_uuid = action.from.player_uuid;
+
+                            if action.reason == Reason::Sacrifice {
+                                add_more_actions!(self.with_moving_card(&mut card, player_uuid, |card, player, game, decider| card.on_sacrifice(action.to.destination, player, game, decider)));
+                            } else {
+                                add_more_actions!(self.with_moving_card(&mut card, player_uuid, |card, player, game, decider| card.on_destroy(action.to.destination, player, game, decider)));
+                            }
+                        },
 
-                        // If the card was moved to a player's hand
-                        (_, Hand) => { add_more_actions!(card.on_return_to_hand(action.to.destination, self.p(action.to.player_uuid), self)); },
+                        // If the card was returned to a player's hand, and not drawn or stolen
+                        (_, Hand) => {
+                            let player_uuid = action.to.player_uuid;
+
+                            if action.reason == Reason::Return {
+                                add_more_actions!(self.with_moving_card(&mut card, player_uuid, |card, player, game, decider| card.on_return_to_hand(action.to.destination, player, game, decider)));
+                            }
+                        },
 
                         // --- stuff that this match doesn't have to care about ---
 
@@ -246,16 +1176,10 @@ impl Game {
                         // Moved back to nursery|drawpile needs no special action
                         (_, Nursery) | (_, Drawpile) => {},
 
-                        // Everything to Stage|UpdateDrawNumber can't reach this match
-                        (_, Stage) | (_, UpdateDrawNumber) => unreachable!(),
-
                         // --- stuff that's illegal ---
 
-                        // Discarded from other locations shouldn't be possible
-                        (Discard, Discard) | (Drawpile, Discard) | (Nursery, Discard) => panic!("Invalid from/to pair"),
-
-                        // Everything from Stage and UpdateDrawNumber is illegal
-                        (Stage, _) | (UpdateDrawNumber, _) => panic!("Invalid from location"),
+                        // Illegal pairs have already been refused by is_valid_move
+                        (Discard, Discard) | (Drawpile, Discard) | (Nursery, Discard) => unreachable!(),
 
                         // **DO NOT DO THIS**
                         // We explicitly want the compiler to give an error when a new destination is added
@@ -263,194 +1187,457 @@ impl Game {
 
                     }
 
-                    // Add card to "to"
-                    self.list_by_location(&action.to).add_card(card);
+                    let after = card.clone();
+
+                    // Add card to "to", which may shuffle the drawpile
+                    let to = self.list_by_location(&action.to)?;
+                    let to_before = to.uuids();
+                    to.add_card(card);
+                    let to_order = Order { before: to_before, after: to.uuids() };
+
+                    self.journal.record(Change::Move { delta: resolved, before, after, index, from_order, to_order });
                 },
             }
         }
 
-        // Debugging
-        println!("\n### ---\n");
-        println!(" +  self: {:?}", self);
-        println!("################\n\n");
+        // Check if somebody has won after resolving everything
+        self.check_winner();
 
         Ok(())
     }
 
 
     /// Called on the Beginning of Turn phase
-    pub fn bot(&mut self) -> Result<(), &'static str> {
-        if let Some(player_uuid) = self.current_player_uuid() {
-            // Temporarily take player from game to call on_bot on it
-            let mut player = self.process_player(player_uuid).unwrap();
+    pub fn bot(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::RunPhase(Phase::Beginning), |game| {
+            game.check_run_phase(Phase::Beginning)?;
+
+            if let Some(player_uuid) = game.current_player_uuid() {
+                // Reset draw number and hand limit
+                let default_hand_limit = game.hand_limit;
+                let player = game.player_mut(player_uuid)?;
+                let (may_draw_number, hand_limit) = (player.may_draw_number, player.hand_limit);
+                player.may_draw_number = 1;
+                player.hand_limit = default_hand_limit;
+
+                game.record_limits(player_uuid, may_draw_number, hand_limit);
+
+                // All cards in the stable trigger at the same moment, so the player chooses the order
+                let triggers = game.stable_triggers(player_uuid, |card, player, game, decider| card.on_bot(player, game, decider))?;
+                let actions = game.with_decider(player_uuid, |_game, decider| user_order_triggers(decider, triggers))?;
+
+                game.apply_triggered(actions)?;
+                game.phase_ran = true;
+
+                Ok(())
+            } else {
+                Err(GameError::NoPlayers)
+            }
+        })
+    }
 
-            // Reset draw number
-            player.may_draw_number = 1;
+    /// Called on the Draw phase
+    pub fn draw(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::RunPhase(Phase::Draw), |game| {
+            game.check_run_phase(Phase::Draw)?;
 
-            let actions = player.on_bot(self);
+            if let Some(player_uuid) = game.current_player_uuid() {
+                let actions = game.with_decider(player_uuid, |game, decider| game.p(player_uuid).on_draw(game, decider));
 
-            self.readd_player(player);
+                game.apply_actions(actions?)?;
+                game.phase_ran = true;
 
-            actions.and_then(|actions| self.apply_actions(actions))
-        } else {
-            Err("game#bot: No players")
-        }
+                Ok(())
+            } else {
+                Err(GameError::NoPlayers)
+            }
+        })
     }
 
-    /// Called on the Draw phase
-    pub fn draw(&mut self) -> Result<(), &'static str> {
-        if let Some(player_uuid) = self.current_player_uuid() {
-            // Take player temporarily from game to call on_eot on it
-            let mut player = self.process_player(player_uuid).unwrap();
+    /// Called on the Action phase
+    /// The player either plays a card or draws a card
+    pub fn action(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::RunPhase(Phase::Action), |game| {
+            game.check_phase(Phase::Action)?;
 
-            let actions = player.on_draw(self);
+            if let Some(player_uuid) = game.current_player_uuid() {
+                let choice = game.with_decider(player_uuid, |game, decider| game.p(player_uuid).on_action(game, decider))?;
 
-            self.readd_player(player);
+                match choice {
+                    Some(card_uuid) => game.p_play(player_uuid, card_uuid),
 
-            actions.and_then(|actions| self.apply_actions(actions))
-        } else {
-            Err("game#eot: No players")
-        }
+                    // There is nothing to draw, so the player just skips their action
+                    None if game.table.drawpile.is_empty() => Ok(()),
+
+                    None => game.p_draw(player_uuid),
+                }
+            } else {
+                Err(GameError::NoPlayers)
+            }
+        })
     }
 
     /// Called on the End of Turn phase
-    pub fn eot(&mut self) -> Result<(), &'static str> {
-        if let Some(player_uuid) = self.current_player_uuid() {
-            // Take player temporarily from game to call on_eot on it
-            let mut player = self.process_player(player_uuid).unwrap();
+    pub fn eot(&mut self) -> Result<(), GameError> {
+        self.journaled(Event::RunPhase(Phase::End), |game| {
+            game.check_run_phase(Phase::End)?;
 
-            let actions = player.on_eot(self);
+            if let Some(player_uuid) = game.current_player_uuid() {
+                // All cards in the stable trigger at the same moment, so the player chooses the order
+                let triggers = game.stable_triggers(player_uuid, |card, player, game, decider| card.on_eot(player, game, decider))?;
+                let actions = game.with_decider(player_uuid, |_game, decider| user_order_triggers(decider, triggers))?;
 
-            self.readd_player(player);
+                game.apply_triggered(actions)?;
 
-            actions.and_then(|actions| self.apply_actions(actions))
-        } else {
-            Err("game#eot: No players")
-        }
-    }
-
-    /// Get the UUIDs of the players of this game
-    pub fn player_uuids(&self) -> Vec<u64> {
-        // Apparently the compiler can't go from Keys<&u64> to Vec<u64> directly,
-        // so we need a .map(|&x| x) redirection step to force copying the u64
-        let mut player_uuids: Vec<u64> = self.players.keys().map(|&x| x).collect();
+                // Discard down to the hand limit after all end of turn effects
+                if !game.is_over() {
+                    let actions = game.with_decider(player_uuid, |game, decider| game.p(player_uuid).on_hand_limit(game, decider));
+                    game.apply_actions(actions?)?;
+                }
 
-        if let Some(removed_player_uuid) = self.removed_player_uuid {
-            player_uuids.push(removed_player_uuid);
-        }
+                // A player with enough unicorns at the end of a turn wins, even when nothing happened
+                game.check_winner();
+                game.phase_ran = true;
 
-        // This shouldn't matter, but for testing and debugging matters, we sort the UUIDs
-        player_uuids.sort();
+                Ok(())
+            } else {
+                Err(GameError::NoPlayers)
+            }
+        })
+    }
 
-        player_uuids
+    /// Get the UUIDs of the players of this game in seating order
+    pub fn player_uuids(&self) -> Vec<PlayerUuid> {
+        self.seats.clone()
     }
 
     /// Get the UUIDs of the players of this game, except for player_uuid
-    pub fn player_uuids_without(&self, player_uuid: u64) -> Vec<u64> {
+    pub fn player_uuids_without(&self, player_uuid: PlayerUuid) -> Vec<PlayerUuid> {
         self.player_uuids().into_iter().filter(|&x| x != player_uuid).collect()
     }
 
 
-    /// Allow to temporarily remove a player from the game
-    /// You MUST call readd_player after this
-    pub fn process_player(&mut self, uuid: u64) -> Option<Player> {
-        assert!(self.removed_player_uuid.is_none(), "Can't take several players at the same time for your own sanity.");
-
-        self.removed_player_uuid = Some(uuid);
-
-        self.players.remove(&uuid)
+    /// Get player by uuid, or an UnknownPlayer error
+    pub fn player(&self, player_uuid: PlayerUuid) -> Result<&Player, GameError> {
+        self.players.get(&player_uuid).ok_or(GameError::UnknownPlayer(player_uuid))
     }
 
-    /// Allow to re-add a removed player back into the game
-    pub fn readd_player(&mut self, player: Player) {
-        assert!(self.removed_player_uuid.is_some(), "You didn't take a player so there's nothing to give back.");
-
-        self.players.insert(self.removed_player_uuid.unwrap(), player);
-
-        self.removed_player_uuid = None;
+    /// Get player mutably by uuid, or an UnknownPlayer error
+    pub fn player_mut(&mut self, player_uuid: PlayerUuid) -> Result<&mut Player, GameError> {
+        self.players.get_mut(&player_uuid).ok_or(GameError::UnknownPlayer(player_uuid))
     }
 
-
-
     /// Get player by name NOTE: This unwraps!!
-    pub fn p(&self, player_uuid: u64) -> &Player {
+    pub fn p(&self, player_uuid: PlayerUuid) -> &Player {
         self.players.get(&player_uuid).unwrap()
     }
 
     /// Get player mutably by name NOTE: This unwraps!!
-    pub fn p_mut(&mut self, player_uuid: u64) -> &mut Player {
+    pub fn p_mut(&mut self, player_uuid: PlayerUuid) -> &mut Player {
         self.players.get_mut(&player_uuid).unwrap()
     }
 
     /// Check if the player may play this card
-    pub fn p_may_play(&self, player_uuid: u64, card: &dyn Card) -> Result<bool, &'static str> {
+    pub fn p_may_play(&self, player_uuid: PlayerUuid, card: &dyn Card) -> Result<bool, GameError> {
         // We need to check two things:
         // First the card checks if the player may play it, then the player checks if it may play the card.
         // The first case is for example blocked when a card requires a unicorn in the player's stable.
         // The second case is for example blocked when a card in the player's stable blocks
         //   the player from playing instant cards and the card is one.
-        Ok(card.may_be_played_by(self.p(player_uuid), self) && self.p(player_uuid).may_play(card))
+        // Cards are checked as if they already left the hand, because that's how they're played
+        // (eg. a Unicorn Phoenix may only be played when there is another card left to discard)
+        let mut player = self.player(player_uuid)?.clone();
+        player.hand_mut().take_card(card.uuid());
+
+        Ok(card.may_be_played_by(&player, self) && player.may_play(card))
     }
 
-    /// Make player draw a card
-    pub fn p_draw(&mut self, player_uuid: u64) -> Result<(), &'static str> {
-        if self.table.drawpile.len() >= 1 {
-            // Move a random card (uuid=0) from the drawpile to the player's hand
-            self.apply_actions(vec!{ delta_same_player!(0, player_uuid, Drawpile => Hand) })
-        } else {
-            Err("game#p_draw: There are no more cards to draw")
+    /// Make sure the player may do their action right now
+    /// If the turn isn't driven by step or run_turn, everybody may always do everything
+    fn check_action(&self, player_uuid: PlayerUuid) -> Result<(), GameError> {
+        self.check_not_over()?;
+
+        if self.phase.is_some() {
+            self.check_phase(Phase::Action)?;
+
+            if self.current_player_uuid() != Some(player_uuid) {
+                return Err(GameError::NotAllowed { reason: "It is not the turn of this player" });
+            }
+
+            if self.acted {
+                return Err(GameError::NotAllowed { reason: "Player already did their action this turn" });
+            }
         }
+
+        Ok(())
+    }
+
+    /// Make player draw a card
+    /// When the turn is driven by step or run_turn, this is the action of the Action phase
+    pub fn p_draw(&mut self, player_uuid: PlayerUuid) -> Result<(), GameError> {
+        self.journaled(Event::Draw { player_uuid }, |game| {
+            game.check_action(player_uuid)?;
+
+            if !game.table.drawpile.is_empty() {
+                // Move a random card (CardUuid::RANDOM) from the drawpile to the player's hand
+                game.apply_actions(vec!{ delta_same_player!(CardUuid::RANDOM, player_uuid, Drawpile => Hand) })?;
+
+                game.acted = true;
+
+                Ok(())
+            } else {
+                Err(GameError::EmptyDrawpile)
+            }
+        })
     }
 
     /// Make the player play the given card
-    pub fn p_play(&mut self, player_uuid: u64, card_uuid: u64) -> Result<(), &'static str> {
-        // Take the card from the player to make it able to modify itself without modifying the player and game
-        if let Some(mut card) = self.p_mut(player_uuid).hand_mut().take_card(card_uuid) {
+    /// When the turn is driven by step or run_turn, this is the action of the Action phase
+    pub fn p_play(&mut self, player_uuid: PlayerUuid, card_uuid: CardUuid) -> Result<(), GameError> {
+        self.journaled(Event::Play { player_uuid, card_uuid }, |game| {
+            game.check_action(player_uuid)?;
 
-            // Check if the player may even play this card
-            let result = match self.p_may_play(player_uuid, &*card) {
-                // Get the card's on_play actions
-                Ok(true) => card.on_play(self.p(player_uuid), self),
+            // The Neighs played in the reaction window are undone too if the play fails in the end
+            game.all_or_nothing(|game| {
+                let location = Location { player_uuid, destination: Destination::Hand };
 
-                Ok(false) => Err("game#p_play: Player may not play card"),
+                // Check if the player may even play this card
+                let may_play = {
+                    let card = game.player(player_uuid)?.hand().get_card(card_uuid)
+                        .ok_or_else(|| GameError::UnknownCard { uuid: card_uuid, location: location.clone() })?;
 
-                Err(err) => Err(err),
-            };
+                    game.p_may_play(player_uuid, &**card)
+                };
 
-            // Add card back because we only removed it temporarily
-            self.p_mut(player_uuid).hand_mut().add_card(card);
+                // Take the card from the player to make it able to modify itself without modifying the player and game
+                let order = game.player(player_uuid)?.hand().uuids();
+                let mut card = game.player_mut(player_uuid)?.hand_mut().try_take_card(card_uuid, &location)?;
+                let before = card.clone();
 
-            // Apply the actions
-            result.and_then(|actions| self.apply_actions(actions))
-        } else {
-            Err("game#p_play: Card unknown")
+                let result = match may_play {
+                    // Instant cards can only be played in a reaction window
+                    Ok(true) if card.cardtype() == CardType::Instant => Err(GameError::NotAllowed { reason: "Instant cards can only be played in response to another card" }),
+
+                    // Give the other players the chance to Neigh the card
+                    Ok(true) => {
+                        game.log.push(Event::CardPlayed { player_uuid, card_uuid });
+                        game.notify(|observer, game| observer.on_card_played(game, player_uuid, card_uuid));
+
+                        match game.reaction_window(player_uuid, &*card) {
+                            // The card is stopped and goes to the discard pile without doing anything
+                            Ok(true) => Ok(vec!{ delta_same_player!(card_uuid, player_uuid, Hand => Discard, Play) }),
+
+                            // Get the card's on_play actions
+                            Ok(false) => game.with_decider(player_uuid, |game, decider| card.on_play(game.p(player_uuid), game, decider)),
+
+                            Err(err) => Err(err),
+                        }
+                    },
+
+                    Ok(false) => Err(GameError::NotAllowed { reason: "Player may not play card" }),
+
+                    Err(err) => Err(err),
+                };
+
+                game.record_card_state(&location, before, &*card);
+
+                // Add card back where it was because we only removed it temporarily
+                game.p_mut(player_uuid).hand_mut().add_card(card);
+                game.p_mut(player_uuid).hand_mut().reorder(&order);
+
+                // Apply the actions
+                game.apply_actions(result?)?;
+
+                game.acted = true;
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Give all other players the chance to Neigh the card the player is about to play
+    /// Since a Neigh can be Neigh'd itself, this returns whether the card is stopped in the end
+    fn reaction_window(&mut self, player_uuid: PlayerUuid, card: &dyn Card) -> Result<bool, GameError> {
+        if !card.may_be_neighed() {
+            return Ok(false);
+        }
+
+        for other_uuid in self.player_uuids_without(player_uuid) {
+            // Find the Neighs this player may play
+            let other = self.p(other_uuid);
+            let neigh_uuids: Vec<CardUuid> = other.hand().uuids().into_iter().filter(|&neigh_uuid| {
+                let neigh = other.hand().get_card(neigh_uuid).unwrap();  // CONFIRMED SAFE UNWRAP
+
+                is_neigh(&**neigh) && other.may_play(&**neigh)
+            }).collect();
+
+            if neigh_uuids.is_empty() {
+                continue;
+            }
+
+            let neigh_uuid = self.with_decider(other_uuid, |_game, decider| {
+                if user_choose_bool(decider, "Do you want to Neigh this card?") {
+                    user_choose(decider, "Which card do you want to play?", &neigh_uuids).map(Some)
+                } else {
+                    Ok(None)
+                }
+            })?;
+
+            if let Some(neigh_uuid) = neigh_uuid {
+                // Take the Neigh from the player, because it's being played
+                let order = self.p(other_uuid).hand().uuids();
+                let mut neigh = self.p_mut(other_uuid).hand_mut().take_card(neigh_uuid).unwrap();  // CONFIRMED SAFE UNWRAP
+                let before = neigh.clone();
+
+                self.log.push(Event::CardPlayed { player_uuid: other_uuid, card_uuid: neigh_uuid });
+                self.notify(|observer, game| observer.on_card_played(game, other_uuid, neigh_uuid));
+
+                // Everybody else gets the chance to stop the Neigh
+                let stopped = self.reaction_window(other_uuid, &*neigh);
+
+                let actions = match &stopped {
+                    // A stopped Neigh goes to the discard pile without doing anything
+                    Ok(true) => Ok(vec!{ delta_same_player!(neigh_uuid, other_uuid, Hand => Discard, Play) }),
+
+                    // Otherwise it's played like any other card
+                    Ok(false) => self.with_decider(other_uuid, |game, decider| neigh.on_play(game.p(other_uuid), game, decider)),
+
+                    Err(err) => Err(err.clone()),
+                };
+
+                self.record_card_state(&Location { player_uuid: other_uuid, destination: Destination::Hand }, before, &*neigh);
+
+                // Add the Neigh back where it was because we only removed it temporarily
+                self.p_mut(other_uuid).hand_mut().add_card(neigh);
+                self.p_mut(other_uuid).hand_mut().reorder(&order);
+
+                self.apply_actions(actions?)?;
+
+                if !stopped? {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Has the location been revealed to the player this turn?
+    pub fn is_revealed(&self, location: &Location, player_uuid: PlayerUuid) -> bool {
+        self.revealed.iter().any(|(revealed, viewer_uuid)| revealed == location && *viewer_uuid == player_uuid)
+    }
+
+    /// Get everything the player may know about this game
+    /// The hands of the other players only show how many cards they have, unless the player may see them
+    pub fn view_for(&self, player_uuid: PlayerUuid) -> Result<PlayerView, GameError> {
+        self.player(player_uuid)?;
+
+        fn visible(list: &dyn CardList) -> Vec<VisibleCard> {
+            list.uuids().into_iter().zip(list.ids()).map(|(uuid, id)| VisibleCard { uuid, id }).collect()
+        }
+
+        let players = self.seats.iter().map(|&uuid| {
+            let player = self.p(uuid);
+            let location = Location { player_uuid: uuid, destination: Destination::Hand };
+            let hand_visible = uuid == player_uuid || player.is_hand_visible() || self.is_revealed(&location, player_uuid);
+
+            PlayerInfo {
+                uuid,
+                name: player.name().to_owned(),
+                stable: visible(player.stable()),
+                hand_size: player.hand().len(),
+                hand: if hand_visible { Some(visible(player.hand())) } else { None },
+            }
+        }).collect();
+
+        Ok(PlayerView {
+            player_uuid,
+            players,
+            discard: visible(&self.table.discard),
+            nursery_size: self.table.nursery.len(),
+            drawpile_size: self.table.drawpile.len(),
+            turn: self.turn,
+            current_player_uuid: self.current_player_uuid(),
+            phase: self.phase,
+            outcome: self.outcome.clone(),
+        })
+    }
+
+    /// Find out what would happen if the player played the given card, without changing this game
+    /// Every choice in the hypothetical game, including those of the other players, is made by the given decider
+    pub fn preview_play(&self, player_uuid: PlayerUuid, card_uuid: CardUuid, decider: Box<dyn Decider>) -> Result<Preview, GameError> {
+        let mut game = self.hypothetical(decider);
+        game.resolved = Some(vec!{});
+
+        game.p_play(player_uuid, card_uuid)?;
+
+        Ok(Preview {
+            actions: game.resolved.take().unwrap_or_default(),
+            stables: game.seats.iter().map(|&uuid| (uuid, game.p(uuid).stable().ids())).collect(),
+            hand_sizes: game.seats.iter().map(|&uuid| (uuid, game.p(uuid).hand().len())).collect(),
+            discard: game.table.discard.ids(),
+            outcome: game.outcome,
+        })
+    }
+
+    /// Make a copy of this game in which all choices are made by the given decider
+    /// Nobody may know the order of the drawpile or what will be random later on,
+    /// so the copy shuffles its drawpile and uses its own generators
+    fn hypothetical(&self, decider: Box<dyn Decider>) -> Game {
+        let decider = SharedDecider(Rc::new(RefCell::new(decider)));
+
+        let seed = ::rand::thread_rng().gen();
+        let mut table = self.table.clone();
+        table.drawpile.reseed(seed);
+        table.drawpile.shuffle();
+
+        Game {
+            table,
+            players: self.players.clone(),
+            deciders: self.seats.iter().map(|&uuid| (uuid, Box::new(decider.clone()) as Box<dyn Decider>)).collect(),
+            observers: vec!{},
+            seats: self.seats.clone(),
+            current_seat: self.current_seat,
+            turn: self.turn,
+            phase: self.phase,
+            acted: self.acted,
+            phase_ran: self.phase_ran,
+            hand_limit: self.hand_limit,
+            unicorns_to_win: self.unicorns_to_win,
+            outcome: self.outcome.clone(),
+            revealed: self.revealed.clone(),
+            resolved: None,
+            journal: Journal::default(),
+            log: EventLog::new(),
+            rng: GameRng::new(seed, 0),
+            uuids: self.uuids.clone(),
         }
     }
 
     /// Easy function to make the player play the card with the given ID
     /// Mostly used for ease of testing
-    pub fn p_play_id(&mut self, player_uuid: u64, card_id: CardID) -> Result<(), &'static str> {
-        if let Some(card) = self.p(player_uuid).hand().get_id_card(card_id) {
+    pub fn p_play_id(&mut self, player_uuid: PlayerUuid, card_id: CardID) -> Result<(), GameError> {
+        if let Some(card) = self.player(player_uuid)?.hand().get_id_card(card_id) {
             let card_uuid = card.uuid();
             self.p_play(player_uuid, card_uuid)
         } else {
-            Err("game#p_play_id: No such card")
+            Err(GameError::UnknownCardID { id: card_id, location: Location { player_uuid, destination: Destination::Hand } })
         }
     }
 
     /// Make the player sacrifice the given card
     /// Mostly used for testing
-    pub fn p_sacrifice(&mut self, player_uuid: u64, card_uuid: u64) -> Result<(), &'static str> {
-        self.apply_actions(vec!{ delta_same_player!(card_uuid, player_uuid, Stable => Discard) })
+    pub fn p_sacrifice(&mut self, player_uuid: PlayerUuid, card_uuid: CardUuid) -> Result<(), GameError> {
+        self.apply_actions(vec!{ delta_same_player!(card_uuid, player_uuid, Stable => Discard, Sacrifice) })
     }
 
     /// Shortcut to sacrifice a card id
-    pub fn p_sacrifice_id(&mut self, player_uuid: u64, card_id: CardID) -> Result<(), &'static str> {
-        if let Some(card) = self.p(player_uuid).stable().get_id_card(card_id) {
+    pub fn p_sacrifice_id(&mut self, player_uuid: PlayerUuid, card_id: CardID) -> Result<(), GameError> {
+        if let Some(card) = self.player(player_uuid)?.stable().get_id_card(card_id) {
             let card_uuid = card.uuid();
             self.p_sacrifice(player_uuid, card_uuid)
         } else {
-            Err("game#p_sacrifice_id: No such card")
+            Err(GameError::UnknownCardID { id: card_id, location: Location { player_uuid, destination: Destination::Stable } })
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+use crate::{Card, CardUuid, Delta, GameOutcome, Location, Phase, PlayerUuid};
+
+/// A single change to the game that can be undone and redone
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Change {
+    /// A card moved from one location to another
+    /// The delta is resolved, so a random draw has the UUID of the card that was actually drawn.
+    /// The state of the card is remembered from before it was taken and from when it was put down,
+    /// as well as the index it was taken from and the order of both lists, eg. after the drawpile shuffled.
+    Move { delta: Delta, before: Box<dyn Card>, after: Box<dyn Card>, index: usize, from_order: Order, to_order: Order },
+
+    /// The number of cards a player may draw changed
+    DrawNumber { player_uuid: PlayerUuid, before: u64, after: u64 },
+
+    /// The hand limit of a player changed
+    HandLimit { player_uuid: PlayerUuid, before: u64, after: u64 },
+
+    /// The drawpile was shuffled, from the order of the UUIDs before to the order after
+    Shuffle { before: Vec<CardUuid>, after: Vec<CardUuid> },
+
+    /// The internal state of a card changed while it stayed at the same location
+    /// Example: Unicorn Lasso remembering which card it stole
+    CardState { location: Location, before: Box<dyn Card>, after: Box<dyn Card> },
+
+    /// The turn went on, eg. the phase changed or the player did their action
+    Turn { before: TurnState, after: TurnState },
+}
+
+/// The order of the cards in a list before and after a change
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order {
+    pub before: Vec<CardUuid>,
+    pub after: Vec<CardUuid>,
+}
+
+/// Everything that keeps track of how far the game is
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TurnState {
+    pub current_seat: usize,
+    pub turn: u64,
+    pub phase: Option<Phase>,
+    pub acted: bool,
+    pub phase_ran: bool,
+    pub outcome: Option<GameOutcome>,
+    pub revealed: Vec<(Location, PlayerUuid)>,
+}
+
+/// The log of all changes to the game, grouped per player action
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Journal {
+    /// How many journaled actions are running inside each other
+    depth: usize,
+
+    /// The turn state at the start of the current action
+    turn_state: Option<TurnState>,
+
+    /// The changes of the current action
+    pending: Vec<Change>,
+
+    /// All finished actions that can be undone, the last one first
+    undo: Vec<Vec<Change>>,
+
+    /// All undone actions that can be redone, the last one first
+    redo: Vec<Vec<Change>>,
+}
+
+impl Journal {
+    /// Start a (possibly nested) action
+    /// This returns true if this is the outermost action
+    pub fn begin(&mut self) -> bool {
+        self.depth += 1;
+        self.depth == 1
+    }
+
+    /// Is an action running right now?
+    pub fn is_busy(&self) -> bool {
+        self.depth > 0
+    }
+
+    /// End a (possibly nested) action
+    /// This returns true if this was the outermost action
+    pub fn end(&mut self) -> bool {
+        self.depth -= 1;
+        self.depth == 0
+    }
+
+    /// Remember the turn state at the start of the action
+    pub fn set_turn_state(&mut self, turn_state: TurnState) {
+        self.turn_state = Some(turn_state);
+    }
+
+    /// Get the turn state from the start of the action
+    pub fn take_turn_state(&mut self) -> Option<TurnState> {
+        self.turn_state.take()
+    }
+
+    /// Record a change of the current action
+    pub fn record(&mut self, change: Change) {
+        self.pending.push(change);
+    }
+
+    /// Get the number of changes of the current action
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Forget the last changes of the current action, because they have been rolled back
+    pub fn truncate(&mut self, len: usize) {
+        self.pending.truncate(len);
+    }
+
+    /// Finish the current action, so it can be undone
+    /// Doing something new means the undone actions can't be redone anymore
+    pub fn commit(&mut self) {
+        if !self.pending.is_empty() {
+            self.undo.push(::std::mem::take(&mut self.pending));
+            self.redo.clear();
+        }
+    }
+
+    /// Take the last action to undo it
+    pub fn take_undo(&mut self) -> Option<Vec<Change>> {
+        self.undo.pop()
+    }
+
+    /// Take the last undone action to redo it
+    pub fn take_redo(&mut self) -> Option<Vec<Change>> {
+        self.redo.pop()
+    }
+
+    /// Store an undone action, so it can be redone
+    pub fn push_redo(&mut self, changes: Vec<Change>) {
+        self.redo.push(changes);
+    }
+
+    /// Store a redone action, so it can be undone again
+    pub fn push_undo(&mut self, changes: Vec<Change>) {
+        self.undo.push(changes);
+    }
+
+    /// Can the last action be undone?
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Can the last undone action be redone?
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::new_without_default)]
+
 extern crate rand;
 
 // --- Tests
@@ -8,14 +10,13 @@ pub mod tests;
 // --- Helper functions and macros
 
 /// The default return type of the card functions
-/// This gives a vector of Delta's or an error.
-pub type ResDeltas = Result<Vec<Delta>, &'static str>;
+/// This gives a vector of Actions or an error.
+pub type ResActions = Result<Vec<Action>, GameError>;
 
 mod utils;
 pub use crate::utils::*;
 
 pub mod macros;
-pub use crate::macros::*;
 
 // --- All regular structs and implementations
 
@@ -25,8 +26,8 @@ pub use crate::cardid::CardID;
 pub mod card;
 pub use crate::card::Card;
 
-pub mod cardstage;
-pub use crate::cardstage::CardStage;
+pub mod cardstate;
+pub use crate::cardstate::CardState;
 
 pub mod cardtype;
 pub use crate::cardtype::{ CardType, Unicorn };
@@ -34,6 +35,15 @@ pub use crate::cardtype::{ CardType, Unicorn };
 pub mod cardlist;
 pub use crate::cardlist::CardList;
 
+pub mod continuation;
+pub use crate::continuation::Continuation;
+
+pub mod decider;
+pub use crate::decider::{ Answer, Decider, FirstChoiceDecider, ScriptedDecider };
+
+pub mod deckspec;
+pub use crate::deckspec::DeckSpec;
+
 pub mod delta;
 pub use crate::delta::*;
 
@@ -43,15 +53,45 @@ pub use crate::discard::Discard;
 pub mod drawpile;
 pub use crate::drawpile::Drawpile;
 
+pub mod error;
+pub use crate::error::GameError;
+
+pub mod eventlog;
+pub use crate::eventlog::{ Event, EventLog };
+
 pub mod game;
 pub use crate::game::Game;
 
 pub mod hand;
 pub use crate::hand::Hand;
 
+pub mod journal;
+pub use crate::journal::{ Change, Journal, Order, TurnState };
+
+pub mod observer;
+pub use crate::observer::{ GameObserver, LoggingObserver };
+
+pub mod outcome;
+pub use crate::outcome::GameOutcome;
+
+pub mod phase;
+pub use crate::phase::Phase;
+
 pub mod player;
 pub use crate::player::Player;
 
+pub mod preview;
+pub use crate::preview::Preview;
+
+pub mod registry;
+pub use crate::registry::{ CardInfo, CardRegistry, Expansion };
+
+pub mod rng;
+pub use crate::rng::GameRng;
+
+#[cfg(feature = "serde")]
+pub mod save;
+
 pub mod nursery;
 pub use crate::nursery::Nursery;
 
@@ -61,6 +101,12 @@ pub use crate::stable::Stable;
 pub mod table;
 pub use crate::table::Table;
 
+pub mod uuid;
+pub use crate::uuid::{ CardUuid, PlayerUuid, UuidAllocator };
+
+pub mod view;
+pub use crate::view::{ PlayerInfo, PlayerView, VisibleCard };
+
 // --- Last set of files to load
 
 pub mod cards;
@@ -2,31 +2,39 @@
 #[macro_export]
 macro_rules! defcard {
     ($structname:ident) => {
-        use crate::uuid;
+        use $crate::CardUuid;
 
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct $structname {
-            uuid: u64,
+            uuid: CardUuid,
         }
 
         impl $structname {
-            pub fn new() -> Self {
+            pub fn new(uuid: CardUuid) -> Self {
                 Self {
-                    uuid: uuid(),
+                    uuid,
                 }
             }
         }
     };
 }
 
-/// Shortcut to make a delta
+/// Shortcut to make an action that moves a card
+/// The reason is optional, without one the usual reason for the move is used (see Reason::of)
 #[macro_export]
 macro_rules! delta {
     ($card_uuid:expr, [$playerfrom_uuid:expr, $from:ident] => [$playerto_uuid:expr, $to:ident]) => {{
         #[allow(unused_imports)]
         use Destination::*;
 
-        Delta {
+        let reason = $crate::Reason::of($from, $to);
+        delta!($card_uuid, [$playerfrom_uuid, $from] => [$playerto_uuid, $to], reason)
+    }};
+    ($card_uuid:expr, [$playerfrom_uuid:expr, $from:ident] => [$playerto_uuid:expr, $to:ident], $reason:expr) => {{
+        #[allow(unused_imports)]
+        use Destination::*;
+
+        $crate::Action::Move(Delta {
             card_uuid: $card_uuid,
             from: Location {
                 player_uuid: $playerfrom_uuid,
@@ -36,64 +44,108 @@ macro_rules! delta {
                 player_uuid: $playerto_uuid,
                 destination: $to,
             },
-        }
+            reason: {
+                #[allow(unused_imports)]
+                use $crate::Reason::*;
+
+                $reason
+            },
+        })
     }};
 }
 
-/// Shortcut to make a delta for the same player
+/// Shortcut to make an action that moves a card for the same player
 #[macro_export]
 macro_rules! delta_same_player {
     ($card_uuid:expr, $player_uuid:expr, $from:ident => $to:ident) => (delta!($card_uuid, [$player_uuid, $from] => [$player_uuid, $to]));
+    ($card_uuid:expr, $player_uuid:expr, $from:ident => $to:ident, $reason:expr) => (delta!($card_uuid, [$player_uuid, $from] => [$player_uuid, $to], $reason));
 }
 
 /// Shortcut to do nothing
 #[macro_export]
 macro_rules! delta_nothing { () => (Ok(vec!{})); }
 
-/// Shortcut to go to a different stage
+/// Shortcut to continue the effect of the card at the location with a follow-up step
+/// The step is either a number or a Continuation with data, eg. Continuation::new(2).with_cards(card_uuids)
 #[macro_export]
-macro_rules! delta_stage {
-    ($card_uuid:expr, $player_uuid:expr, $from:ident => $stage:expr) => {{
+macro_rules! delta_continue {
+    ($card_uuid:expr, $player_uuid:expr, $from:ident => $continuation:expr) => {{
         #[allow(unused_imports)]
         use Destination::*;
 
-        Delta {
+        $crate::Action::Continue {
             card_uuid: $card_uuid,
-            from: Location { player_uuid: $player_uuid, destination: $from, },
-            to: Location { player_uuid: $stage, destination: Destination::Stage, }
+            location: Location { player_uuid: $player_uuid, destination: $from, },
+            continuation: $crate::Continuation::from($continuation),
+        }
+    }};
+}
+
+/// Shortcut to change the number of cards the player may draw, eg. delta_draw_number!(player_uuid => By(-1))
+#[macro_export]
+macro_rules! delta_draw_number {
+    ($player_uuid:expr => $change:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::NumberChange::*;
+
+        $crate::Action::DrawNumber { player_uuid: $player_uuid, change: $change }
+    }};
+}
+
+/// Shortcut to change the maximum number of cards the player may have in their hand, eg. delta_hand_limit!(player_uuid => To(0))
+#[macro_export]
+macro_rules! delta_hand_limit {
+    ($player_uuid:expr => $change:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::NumberChange::*;
+
+        $crate::Action::HandLimit { player_uuid: $player_uuid, change: $change }
+    }};
+}
+
+/// Shortcut to show the cards at a location to a player
+#[macro_export]
+macro_rules! delta_reveal {
+    ([$owner_uuid:expr, $at:ident] => $player_uuid:expr) => {{
+        #[allow(unused_imports)]
+        use Destination::*;
+
+        $crate::Action::Reveal {
+            location: Location { player_uuid: $owner_uuid, destination: $at, },
+            player_uuid: $player_uuid,
         }
     }};
 }
 
-/// Shortcut to update the number of cards the player may draw
+/// Shortcut to let the effect of a card fizzle, when it can't be completed anymore
+/// Nothing happens, but the fizzle is remembered in the log
 #[macro_export]
-macro_rules! delta_update_draw_number {
-    ($card_uuid:expr, $player_uuid:expr, $from:ident => $diff:expr) => {{
+macro_rules! delta_fizzle {
+    ($card_uuid:expr, $player_uuid:expr, $from:ident) => {{
         #[allow(unused_imports)]
         use Destination::*;
 
-        Delta {
+        $crate::Action::Fizzle {
             card_uuid: $card_uuid,
-            from: Location { player_uuid: $player_uuid, destination: $from, },
-            to: Location { player_uuid: $diff, destination: Destination::UpdateDrawNumber, }
+            location: Location { player_uuid: $player_uuid, destination: $from, },
         }
     }};
 }
 
-/// Shortcut to get a boxed card of the given type
+/// Shortcut to get a boxed card of the given type, with a new UUID from the given allocator (eg. game.uuids())
 #[macro_export]
 macro_rules! card {
-    ($type:ident) => (Box::new(cards::$type::new()));
+    ($uuids:expr, $type:ident) => (Box::new(cards::$type::new($uuids.card())));
 }
 
-/// Shortcut to get a boxed baby unicorn card of the given theme
+/// Shortcut to get a boxed baby unicorn card of the given theme, with a new UUID from the given allocator
 #[macro_export]
 macro_rules! baby {
-    ($theme:ident) => (Box::new(cards::Baby::new(cards::BabyTheme::$theme)));
+    ($uuids:expr, $theme:ident) => (Box::new(cards::Baby::new($uuids.card(), cards::BabyTheme::$theme)));
 }
 
-/// Shortcut to get a boxed basic unicorn card of the given theme
+/// Shortcut to get a boxed basic unicorn card of the given theme, with a new UUID from the given allocator
 #[macro_export]
 macro_rules! basic {
-    ($theme:ident) => (Box::new(cards::Basic::new(cards::BasicTheme::$theme)));
+    ($uuids:expr, $theme:ident) => (Box::new(cards::Basic::new($uuids.card(), cards::BasicTheme::$theme)));
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+use crate::{Action, CardUuid, Delta, Game, GameError, GameOutcome, Location, Phase, PlayerUuid};
+
+/// A GameObserver is told about everything that happens in a game, eg. to show it in a UI,
+/// to collect statistics or to record a replay
+///
+/// Observers only get to look at the game, they can't change it. They are told about things
+/// as they happen, so something may still be rolled back afterwards when an error is raised.
+/// All callbacks do nothing by default, so an observer only has to implement what it cares about.
+pub trait GameObserver: ::std::fmt::Debug {
+    /// The turn of the player starts
+    fn on_turn_start(&mut self, _game: &Game, _turn: u64, _player_uuid: PlayerUuid) {}
+
+    /// The turn of the player ended
+    fn on_turn_end(&mut self, _game: &Game, _turn: u64, _player_uuid: PlayerUuid) {}
+
+    /// The phase of the current turn starts
+    fn on_phase(&mut self, _game: &Game, _phase: Phase) {}
+
+    /// The player plays the card, before anybody gets the chance to Neigh it
+    fn on_card_played(&mut self, _game: &Game, _player_uuid: PlayerUuid, _card_uuid: CardUuid) {}
+
+    /// The card moved, which is given by the resolved delta (so random draws say which card was drawn)
+    fn on_card_moved(&mut self, _game: &Game, _delta: &Delta) {}
+
+    /// The card triggered the actions, which are resolved next
+    fn on_trigger(&mut self, _game: &Game, _card_uuid: CardUuid, _actions: &[Action]) {}
+
+    /// The effect of the card couldn't be completed
+    fn on_fizzle(&mut self, _game: &Game, _card_uuid: CardUuid, _location: &Location) {}
+
+    /// Somebody won the game
+    fn on_win(&mut self, _game: &Game, _outcome: &GameOutcome) {}
+
+    /// A command failed with the error, after the game was rolled back
+    fn on_error(&mut self, _game: &Game, _error: &GameError) {}
+}
+
+/// Observer that prints everything that happens to stdout, eg. for debugging
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoggingObserver;
+
+impl GameObserver for LoggingObserver {
+    fn on_turn_start(&mut self, _game: &Game, turn: u64, player_uuid: PlayerUuid) {
+        println!("\n\n################");
+        println!("### turn {} of {:?}", turn, player_uuid);
+    }
+
+    fn on_turn_end(&mut self, game: &Game, turn: u64, player_uuid: PlayerUuid) {
+        println!("\n### end of turn {} of {:?}", turn, player_uuid);
+        println!(" +  game: {:?}", game);
+        println!("################\n\n");
+    }
+
+    fn on_phase(&mut self, _game: &Game, phase: Phase) {
+        println!("### phase {:?}", phase);
+    }
+
+    fn on_card_played(&mut self, _game: &Game, player_uuid: PlayerUuid, card_uuid: CardUuid) {
+        println!("### {:?} plays {:?}", player_uuid, card_uuid);
+    }
+
+    fn on_card_moved(&mut self, game: &Game, delta: &Delta) {
+        println!("### apply action");
+        println!(" +    game: {:?}\n", game);
+        println!(" +    action: {:?}\n", delta);
+    }
+
+    fn on_trigger(&mut self, game: &Game, card_uuid: CardUuid, actions: &[Action]) {
+        println!("### {:?} triggers", card_uuid);
+        println!(" +    game: {:?}\n", game);
+        println!(" +    actions: {:?}\n", actions);
+    }
+
+    fn on_fizzle(&mut self, _game: &Game, card_uuid: CardUuid, location: &Location) {
+        println!("### {:?} fizzles at {:?}", card_uuid, location);
+    }
+
+    fn on_win(&mut self, _game: &Game, outcome: &GameOutcome) {
+        println!("### {:?} wins in turn {}", outcome.winner, outcome.turn);
+    }
+
+    fn on_error(&mut self, _game: &Game, error: &GameError) {
+        println!("### error: {:?}", error);
+    }
+}
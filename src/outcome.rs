@@ -0,0 +1,17 @@
+use crate::{CardID, PlayerUuid};
+
+use std::collections::HashMap;
+
+/// The result of a finished game
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOutcome {
+    /// The UUID of the player who won
+    pub winner: PlayerUuid,
+
+    /// The number of the turn in which the game was won
+    pub turn: u64,
+
+    /// The IDs of the cards in every player's stable at the end of the game
+    pub stables: HashMap<PlayerUuid, Vec<CardID>>,
+}
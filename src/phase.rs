@@ -0,0 +1,31 @@
+/// The phases of a turn, in the order in which they are played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    /// Beginning of Turn: all "at the beginning of your turn" effects are triggered
+    Beginning,
+
+    /// Draw: the player draws a card (or more if their stable allows it)
+    Draw,
+
+    /// Action: the player either plays one card or draws one card
+    Action,
+
+    /// End of Turn: all "at the end of your turn" effects are triggered
+    End,
+}
+
+impl Phase {
+    /// Get the phase that comes after this one
+    /// After the End of Turn phase, the next turn starts at the Beginning of Turn phase
+    pub fn next(self) -> Phase {
+        use Phase::*;
+
+        match self {
+            Beginning => Draw,
+            Draw => Action,
+            Action => End,
+            End => Beginning,
+        }
+    }
+}
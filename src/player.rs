@@ -1,8 +1,9 @@
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
-    uuid: u64,
+    uuid: PlayerUuid,
 
     name: String,
     hand: Hand,
@@ -12,22 +13,27 @@ pub struct Player {
     /// This can be increased by cards (eg. Extra Tail) and
     ///   is reset by the Game before player.on_bot is called
     pub may_draw_number: u64,
+
+    /// The maximum number of cards this player may have in their hand at the end of their turn
+    /// This can be changed by cards and is reset by the Game before player.on_bot is called
+    pub hand_limit: u64,
 }
 
 impl Player {
-    pub fn new(name: String) -> Player {
+    pub fn new(uuid: PlayerUuid, name: String) -> Player {
         Player {
-            uuid: uuid(),
+            uuid,
             name: name,
             hand: Hand::new(),
             stable: Stable::new(),
 
             may_draw_number: 1,
+            hand_limit: 7,
         }
     }
 
     /// Get UUID
-    pub fn uuid(&self) -> u64 {
+    pub fn uuid(&self) -> PlayerUuid {
         self.uuid
     }
 
@@ -67,32 +73,8 @@ impl Player {
         self.stable.owner_may_play(card)
     }
 
-    /// Called on the beginning of turn
-    pub fn on_bot(&mut self, game: &Game) -> ResDeltas {
-        let mut actions = vec!{};
-
-        self.stable.reset_cards();
-
-        // Take card from stable temporarily to call on_bot
-        while let Some(mut card) = self.stable.next_card() {
-            match card.on_bot(self, game) {
-                Err(err) => { return Err(err); },
-
-                Ok(mut card_actions) => {
-                    // Put the card back in the stable
-                    self.stable.readd_card(card);
-
-                    // Add this card's actions
-                    actions.append(&mut card_actions);
-                },
-            }
-        }
-
-        Ok(actions)
-    }
-
     /// Called on the draw phase
-    pub fn on_draw(&mut self, game: &Game) -> ResDeltas {
+    pub fn on_draw(&self, game: &Game, decider: &mut dyn Decider) -> ResActions {
         let upperbound = ::std::cmp::min(self.may_draw_number as usize, game.table.drawpile.len());
 
         let number = {
@@ -103,49 +85,47 @@ impl Player {
                 // This is reversed because players probably want to draw as many cards as possible
                 // Just kidding, it's because we always choose the first one in testing and the
                 // tests rely on the player drawing as many cards as allowed
-                *user_choose("How many cards do you want to draw?", &(1..=upperbound).rev().collect())
+                let numbers: Vec<u64> = (1..=upperbound as u64).rev().collect();
+                user_choose(decider, "How many cards do you want to draw?", &numbers)? as usize
             }
         };
 
         // Draw `draw` cards
-        Ok((0..number).map(|_| delta_same_player!(0, self.uuid(), Drawpile => Hand)).collect())
+        Ok((0..number).map(|_| delta_same_player!(CardUuid::RANDOM, self.uuid(), Drawpile => Hand)).collect())
     }
 
     /// Called on the action phase
-    pub fn on_action(&mut self, game: &Game) -> ResDeltas {
+    /// Gives the UUID of the card the player wants to play, or None if they want to draw a card instead
+    pub fn on_action(&self, game: &Game, decider: &mut dyn Decider) -> Result<Option<CardUuid>, GameError> {
+        // Find all cards the player may play right now
+        // Instant cards can only be played in response to another card
+        let card_uuids: Vec<CardUuid> = self.hand.uuids().into_iter().filter(|&card_uuid| {
+            let card = self.hand.get_card(card_uuid).unwrap();  // CONFIRMED SAFE UNWRAP
+
+            card.cardtype() != CardType::Instant && game.p_may_play(self.uuid, &**card) == Ok(true)
+        }).collect();
+
         // Let the player choose between playing a card and drawing another card
-        if user_choose_bool("Do you want to play a card?") {
-            unimplemented!("Choose a card and play it")
+        if !card_uuids.is_empty() && user_choose_bool(decider, "Do you want to play a card?") {
+            user_choose(decider, "Which card do you want to play?", &card_uuids).map(Some)
         } else {
-            // Reset draw number to 1
-            self.may_draw_number = 1;
-
-            // Draw another card
-            self.on_draw(game)
+            Ok(None)
         }
     }
 
-    /// Called on the end of turn
-    pub fn on_eot(&mut self, game: &Game) -> ResDeltas {
-        let mut actions = vec!{};
-
-        self.stable.reset_cards();
+    /// Called on the end of turn, after the on_eot actions are applied
+    /// The player has to discard cards until they have no more cards than their hand limit
+    pub fn on_hand_limit(&self, _game: &Game, decider: &mut dyn Decider) -> ResActions {
+        let hand_uuids = self.hand.uuids();
 
-        // @TODO @FIXME @HACK take cards from stable temporarily to call on_bot
-        while let Some(mut card) = self.stable.next_card() {
-            match card.on_eot(self, game) {
-                Err(err) => { return Err(err); },
+        if hand_uuids.len() as u64 > self.hand_limit {
+            let number = hand_uuids.len() - self.hand_limit as usize;
+            let card_uuids = user_choose_n(decider, "Which cards do you want to discard?", &hand_uuids, number)?;
 
-                Ok(mut card_actions) => {
-                    // Put the card back in the stable
-                    self.stable.readd_card(card);
-
-                    // Add this card's actions
-                    actions.append(&mut card_actions);
-                },
-            }
+            // Move the chosen cards from the player's hand to the discard pile
+            Ok(card_uuids.into_iter().map(|card_uuid| delta_same_player!(card_uuid, self.uuid(), Hand => Discard)).collect())
+        } else {
+            delta_nothing!()
         }
-
-        Ok(actions)
     }
 }
\ No newline at end of file
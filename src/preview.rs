@@ -0,0 +1,24 @@
+use crate::{Action, CardID, GameOutcome, PlayerUuid};
+
+use std::collections::HashMap;
+
+/// What would happen if a player played a card, see Game::preview_play
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preview {
+    /// All actions that would be applied, in order, including the ones from triggers
+    /// Random draws are resolved to the UUID of a random card of the drawpile, which
+    /// isn't necessarily the card that would really be drawn
+    pub actions: Vec<Action>,
+
+    /// The IDs of the cards in every player's stable afterwards
+    pub stables: HashMap<PlayerUuid, Vec<CardID>>,
+
+    /// The number of cards in every player's hand afterwards
+    pub hand_sizes: HashMap<PlayerUuid, usize>,
+
+    /// The IDs of the cards in the discard pile afterwards
+    pub discard: Vec<CardID>,
+
+    /// The outcome of the game, if the card would make somebody win
+    pub outcome: Option<GameOutcome>,
+}
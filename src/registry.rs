@@ -0,0 +1,157 @@
+use crate::*;
+
+use crate::cards::{ BabyTheme, BasicTheme };
+
+use std::sync::OnceLock;
+
+/// Function that builds a card with the given UUID and state
+/// This gives None if the state doesn't belong to the card
+pub type Factory = fn(CardUuid, CardState) -> Option<Box<dyn Card>>;
+
+/// The expansion a card comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expansion {
+    /// The base game
+    Base,
+}
+
+/// Everything there is to know about a card without building it
+#[derive(Debug, Clone, Copy)]
+pub struct CardInfo {
+    pub id: CardID,
+    pub cardtype: CardType,
+
+    /// The name and rules text of the card
+    /// Themed cards (eg. Basic Unicorns) can have a different name or text for every theme
+    pub name: &'static str,
+    pub description: &'static str,
+
+    /// The number of copies of this card in the official deck of its expansion
+    pub count: usize,
+    pub expansion: Expansion,
+
+    /// Builds the card
+    /// Cards that don't need a theme are built fresh with CardState::Stateless
+    pub factory: Factory,
+}
+
+impl CardInfo {
+    /// Build a new card with a new UUID
+    pub fn build(&self, uuids: &mut UuidAllocator, state: CardState) -> Option<Box<dyn Card>> {
+        (self.factory)(uuids.card(), state)
+    }
+}
+
+/// Shortcut to make the factory of a card that doesn't remember anything
+macro_rules! stateless {
+    ($type:ident) => (|uuid, state| match state {
+        CardState::Stateless => Some(Box::new(cards::$type::new(uuid))),
+        _ => None,
+    });
+}
+
+/// Shortcut to make the factory of a card that remembers what it stole
+macro_rules! steal {
+    ($type:ident) => (|uuid, state| match state {
+        CardState::Stateless => Some(Box::new(cards::$type::with_state(uuid, None, None))),
+        CardState::Steal { target_uuid, stolen_card_uuid } => Some(Box::new(cards::$type::with_state(uuid, target_uuid, stolen_card_uuid))),
+        _ => None,
+    });
+}
+
+/// The list of all cards there are
+/// Nothing has to build a card to find out what it is, except the registry itself (once)
+pub struct CardRegistry;
+
+impl CardRegistry {
+    /// Get all cards, grouped per expansion and type
+    pub fn all() -> &'static [CardInfo] {
+        static CARDS: OnceLock<Vec<CardInfo>> = OnceLock::new();
+
+        CARDS.get_or_init(|| {
+            use Expansion::*;
+
+            // The ID, count, expansion and factory of every card,
+            // and a state to build an example of the card with to get the rest of its info
+            let cards: Vec<(CardID, usize, Expansion, Factory, CardState)> = vec!{
+                // Baby and Basic Unicorns (with a single Narwhal)
+                (CardID::Baby, 13, Base, |uuid, state| match state {
+                    CardState::Baby(theme) => Some(Box::new(cards::Baby::new(uuid, theme))),
+                    _ => None,
+                }, CardState::Baby(BabyTheme::Pink)),
+                (CardID::Basic, 22, Base, |uuid, state| match state {
+                    CardState::Basic(theme) => Some(Box::new(cards::Basic::new(uuid, theme))),
+                    _ => None,
+                }, CardState::Basic(BasicTheme::Dancer)),
+
+                // Magical Unicorns
+                (CardID::GinormousUnicorn, 1, Base, stateless!(GinormousUnicorn), CardState::Stateless),
+                (CardID::NarwhalTorpedo, 1, Base, stateless!(NarwhalTorpedo), CardState::Stateless),
+                (CardID::SeductiveUnicorn, 1, Base, steal!(SeductiveUnicorn), CardState::Stateless),
+                (CardID::UnicornPhoenix, 1, Base, stateless!(UnicornPhoenix), CardState::Stateless),
+
+                // Magic
+                (CardID::BlatantThievery, 1, Base, stateless!(BlatantThievery), CardState::Stateless),
+                (CardID::GoodDeal, 1, Base, stateless!(GoodDeal), CardState::Stateless),
+                (CardID::ResetButton, 1, Base, stateless!(ResetButton), CardState::Stateless),
+                (CardID::ShakeUp, 1, Base, stateless!(ShakeUp), CardState::Stateless),
+                (CardID::TargetedDestruction, 1, Base, stateless!(TargetedDestruction), CardState::Stateless),
+                (CardID::TwoForOne, 2, Base, stateless!(TwoForOne), CardState::Stateless),
+                (CardID::UnfairBargain, 2, Base, stateless!(UnfairBargain), CardState::Stateless),
+                (CardID::UnicornPoison, 3, Base, stateless!(UnicornPoison), CardState::Stateless),
+
+                // Upgrades
+                (CardID::ExtraTail, 3, Base, stateless!(ExtraTail), CardState::Stateless),
+                (CardID::GlitterBomb, 2, Base, stateless!(GlitterBomb), CardState::Stateless),
+                (CardID::RainbowAura, 1, Base, stateless!(RainbowAura), CardState::Stateless),
+                (CardID::RainbowMane, 3, Base, stateless!(RainbowMane), CardState::Stateless),
+                (CardID::SummoningRitual, 1, Base, stateless!(SummoningRitual), CardState::Stateless),
+                (CardID::UnicornLasso, 1, Base, steal!(UnicornLasso), CardState::Stateless),
+
+                // Instants
+                (CardID::Neigh, 14, Base, stateless!(Neigh), CardState::Stateless),
+                (CardID::SuperNeigh, 1, Base, stateless!(SuperNeigh), CardState::Stateless),
+
+                // Downgrades
+                (CardID::BarbedWire, 1, Base, stateless!(BarbedWire), CardState::Stateless),
+                (CardID::BrokenStable, 1, Base, stateless!(BrokenStable), CardState::Stateless),
+                (CardID::NannyCam, 1, Base, stateless!(NannyCam), CardState::Stateless),
+                (CardID::SadisticRitual, 1, Base, stateless!(SadisticRitual), CardState::Stateless),
+                (CardID::Slowdown, 1, Base, stateless!(Slowdown), CardState::Stateless),
+                (CardID::TinyStable, 1, Base, stateless!(TinyStable), CardState::Stateless),
+            };
+
+            cards.into_iter().map(|(id, count, expansion, factory, state)| {
+                // The UUID of the example doesn't matter, it's thrown away immediately
+                let card = factory(CardUuid(0), state).unwrap();  // CONFIRMED SAFE UNWRAP
+
+                CardInfo {
+                    id,
+                    cardtype: card.cardtype(),
+                    name: card.name(),
+                    description: card.description(),
+                    count,
+                    expansion,
+                    factory,
+                }
+            }).collect()
+        })
+    }
+
+    /// Get the info of the card with this ID
+    pub fn get(id: CardID) -> &'static CardInfo {
+        // Every card is registered, which is checked by the tests
+        CardRegistry::all().iter().find(|info| info.id == id).unwrap()
+    }
+
+    /// Get all cards of an expansion
+    pub fn expansion(expansion: Expansion) -> impl Iterator<Item = &'static CardInfo> {
+        CardRegistry::all().iter().filter(move |info| info.expansion == expansion)
+    }
+
+    /// Build a card again from its ID, UUID and state, eg. when loading a saved game
+    /// This gives None if the state doesn't belong to a card with this ID
+    pub fn restore(id: CardID, uuid: CardUuid, state: CardState) -> Option<Box<dyn Card>> {
+        (CardRegistry::get(id).factory)(uuid, state)
+    }
+}
@@ -0,0 +1,57 @@
+use rand::{ RngCore, SeedableRng };
+use rand_chacha::ChaCha20Rng;
+
+/// The random number generator of a game
+/// Everything random in a game comes from a GameRng, so two games with the same seed are exactly the same
+///
+/// A game can use several generators with the same seed, as long as every one of them uses
+/// a different stream (eg. one for the drawpile and one for everything else).
+#[derive(Debug, Clone)]
+pub struct GameRng {
+    seed: u64,
+    stream: u64,
+    rng: ChaCha20Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64, stream: u64) -> GameRng {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        rng.set_stream(stream);
+        // Fill the first block right away, as the word position of a generator
+        // that hasn't generated anything yet can't be read
+        rng.set_word_pos(0);
+
+        GameRng { seed, stream, rng }
+    }
+
+    /// Get the seed
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get the stream
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Get how many words have been generated, to be able to continue from there later
+    pub fn word_pos(&self) -> u128 {
+        self.rng.get_word_pos()
+    }
+
+    /// Continue generating from the given word
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.rng.set_word_pos(word_pos);
+    }
+
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 { self.rng.next_u32() }
+
+    fn next_u64(&mut self) -> u64 { self.rng.next_u64() }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) { self.rng.fill_bytes(dest) }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ::rand::Error> { self.rng.try_fill_bytes(dest) }
+}
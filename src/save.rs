@@ -0,0 +1,77 @@
+use crate::*;
+
+use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+use serde::de::Error;
+
+/// A card as it is saved
+/// Loading builds the card again from these with CardRegistry::restore
+#[derive(Serialize, Deserialize)]
+struct SavedCard {
+    id: CardID,
+    uuid: CardUuid,
+    state: CardState,
+}
+
+impl Serialize for Box<dyn Card> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedCard { id: self.id(), uuid: self.uuid(), state: self.state() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn Card> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Box<dyn Card>, D::Error> {
+        let saved = SavedCard::deserialize(deserializer)?;
+
+        CardRegistry::restore(saved.id, saved.uuid, saved.state)
+            .ok_or_else(|| D::Error::custom(format!("{:?} can't have the state {:?}", saved.id, saved.state)))
+    }
+}
+
+/// A random number generator as it is saved
+/// It continues where it was when it's loaded, so a loaded game stays exactly the same as the saved one
+#[derive(Serialize, Deserialize)]
+struct SavedRng {
+    seed: u64,
+    stream: u64,
+    word_pos: u128,
+}
+
+impl Serialize for GameRng {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedRng { seed: self.seed(), stream: self.stream(), word_pos: self.word_pos() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameRng {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameRng, D::Error> {
+        let saved = SavedRng::deserialize(deserializer)?;
+
+        let mut rng = GameRng::new(saved.seed, saved.stream);
+        rng.set_word_pos(saved.word_pos);
+        Ok(rng)
+    }
+}
+
+impl Game {
+    /// Save the game as JSON
+    pub fn to_json(&self) -> Result<String, GameError> {
+        serde_json::to_string(self).map_err(|err| GameError::InvalidSave { reason: err.to_string() })
+    }
+
+    /// Load a game that was saved as JSON
+    /// The deciders of the players aren't saved, so they have to be set again
+    pub fn from_json(json: &str) -> Result<Game, GameError> {
+        serde_json::from_str(json).map_err(|err| GameError::InvalidSave { reason: err.to_string() })
+    }
+
+    /// Save the game in a compact binary format
+    pub fn to_bytes(&self) -> Result<Vec<u8>, GameError> {
+        bincode::serialize(self).map_err(|err| GameError::InvalidSave { reason: err.to_string() })
+    }
+
+    /// Load a game that was saved in the binary format
+    /// The deciders of the players aren't saved, so they have to be set again
+    pub fn from_bytes(bytes: &[u8]) -> Result<Game, GameError> {
+        bincode::deserialize(bytes).map_err(|err| GameError::InvalidSave { reason: err.to_string() })
+    }
+}
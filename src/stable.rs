@@ -1,20 +1,15 @@
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stable {
     cards: Vec<Box<dyn Card>>,
-    processing: Option<u64>,
-
-    had: Vec<u64>,
 }
 
 impl Stable {
     pub fn new() -> Stable {
         Stable {
             cards: vec! {},
-            processing: None,
-
-            had: vec! {},
         }
     }
 
@@ -38,38 +33,17 @@ impl Stable {
         self.cards.iter().all(|c| c.other_may_destroy(card))
     }
 
-
-    /// Reset the processed state of all cards
-    pub fn reset_cards(&mut self) {
-        self.had.clear();
-    }
-
-    /// Find a card that isn't processed yet
-    /// It is the CALLER's responsibility to call `readd_card` when done processing
-    /// @HACK @TODO @FIXME this is a REALLY dirty hack
-    pub fn next_card(&mut self) -> Option<Box<dyn Card>> {
-        // Find a card we haven't processed
-        if let Some(card) = self.cards.iter().find(|c| !self.had.contains(&c.uuid())) {
-            // Get UUID of the card
-            let uuid = card.uuid();
-
-            // Take card from stable for processing
-            let card = self.process_card(uuid).unwrap();
-
-            // Store that we've processed it
-            self.had.push(uuid);
-
-            // Give the card to the caller to process it
-            Some(card)
-        } else {
-            None
-        }
+    /// Get the UUIDs of the cards that match the filter and may be destroyed by another player
+    /// Example: Unicorn Poison may only target the Unicorns that aren't protected by a Rainbow Aura
+    pub fn destroyable_uuids(&self, filter: fn(card: &dyn Card) -> bool) -> Vec<CardUuid> {
+        self.cards.iter()
+            .filter(|card| filter(&***card) && self.other_may_destroy(&***card))
+            .map(|card| card.uuid())
+            .collect()
     }
 }
 
 impl CardList for Stable {
     fn __list(&self) -> &Vec<Box<dyn Card>> { &self.cards }
     fn __list_mut(&mut self) -> &mut Vec<Box<dyn Card>> { &mut self.cards }
-    fn __processing(&self) -> Option<u64> { self.processing }
-    fn __processing_mut(&mut self, new: Option<u64>) -> Option<u64> { ::std::mem::replace(&mut self.processing, new) }
 }
@@ -1,6 +1,7 @@
 use crate::{Discard, Drawpile, Nursery};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table {
     pub discard: Discard,
     pub drawpile: Drawpile,
@@ -15,4 +16,12 @@ impl Table {
             nursery: Nursery::new(),
         }
     }
+
+    /// Make a table whose drawpile always shuffles the same way for the same seed
+    pub fn with_seed(seed: u64) -> Table {
+        Table {
+            drawpile: Drawpile::with_seed(seed),
+            ..Table::new()
+        }
+    }
 }
\ No newline at end of file
@@ -18,11 +18,13 @@ pub fn test_baby() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    let player = game.p_mut(uuid1);
-    player.hand_mut().add_card(baby!(Yellow));
-    player.hand_mut().add_card(baby!(White));
-    player.hand_mut().add_card(baby!(Purple));
-    player.hand_mut().add_card(baby!(Black));
+    let cards: Vec<Box<dyn Card>> = vec!{
+        baby!(game.uuids(), Yellow),
+        baby!(game.uuids(), White),
+        baby!(game.uuids(), Purple),
+        baby!(game.uuids(), Black),
+    };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
 
     assert_eq!(game.p_play_id(uuid1, Baby).unwrap(), ());
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Baby });
@@ -41,11 +43,14 @@ pub fn test_shakeup() {
     let uuid1 = game.ez_new_player("Tester");
 
     for _ in 0..3 {
-        game.p_mut(uuid1).hand_mut().add_card(basic!(Narwhal));
+        let card = basic!(game.uuids(), Narwhal);
+        game.p_mut(uuid1).hand_mut().add_card(card);
     }
     for _ in 0..10 {
-        game.table.drawpile.add_card(card!(ShakeUp));
-        game.table.discard.add_card(card!(RainbowAura));
+        let card = card!(game.uuids(), ShakeUp);
+        game.table.drawpile.add_card(card);
+        let card = card!(game.uuids(), RainbowAura);
+        game.table.discard.add_card(card);
     }
 
     assert_eq!(game.p_draw(uuid1).unwrap(), ());
@@ -68,14 +73,17 @@ pub fn test_narwhal_torpedo() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    let stable = game.p_mut(uuid1).stable_mut();
     for _ in 0..3 {
-        stable.add_card(basic!(Narwhal));
-        stable.add_card(card!(RainbowAura));
-        stable.add_card(card!(BrokenStable));
+        let cards: Vec<Box<dyn Card>> = vec!{
+            basic!(game.uuids(), Narwhal),
+            card!(game.uuids(), RainbowAura),
+            card!(game.uuids(), BrokenStable),
+        };
+        game.p_mut(uuid1).stable_mut().add_all(cards);
     }
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(NarwhalTorpedo));
+    let card = card!(game.uuids(), NarwhalTorpedo);
+    game.p_mut(uuid1).hand_mut().add_card(card);
 
     // Narwhal Torpedo: When this card enters your Stable, SACRIFICE all Downgrade cards
     assert_eq!(game.p_play_id(uuid1, NarwhalTorpedo).unwrap(), ());
@@ -107,14 +115,18 @@ pub fn test_sadistic_ritual() {
         };
     }
 
-    game.p_mut(uuid1).stable_mut().add_card(basic!(Dancer));
-    game.p_mut(uuid1).stable_mut().add_card(card!(SadisticRitual));
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), SadisticRitual);
+    game.p_mut(uuid1).stable_mut().add_card(card);
 
     // We depend on the order of the drawpile for testing, so we disable auto-shuffle
     // before adding cards to the drawpile
     game.table.drawpile.auto_shuffle = false;
-    game.table.drawpile.add_card(card!(BrokenStable));
-    game.table.drawpile.add_card(card!(GinormousUnicorn));
+    let card = card!(game.uuids(), BrokenStable);
+    game.table.drawpile.add_card(card);
+    let card = card!(game.uuids(), GinormousUnicorn);
+    game.table.drawpile.add_card(card);
 
     // Hand:
     // Stable: Basic(Dancer), SadisticRitual
@@ -224,13 +236,15 @@ pub fn test_barbed_wire() {
     }
 
 
-    let hand = game.p_mut(uuid1).hand_mut();
-    hand.add_card(card!(RainbowAura));
-    hand.add_card(card!(GinormousUnicorn));
-    hand.add_card(card!(Slowdown));
-    hand.add_card(card!(BarbedWire));
-    hand.add_card(card!(RainbowAura));
-    hand.add_card(basic!(PumpkinSpice));
+    let cards: Vec<Box<dyn Card>> = vec!{
+        card!(game.uuids(), RainbowAura),
+        card!(game.uuids(), GinormousUnicorn),
+        card!(game.uuids(), Slowdown),
+        card!(game.uuids(), BarbedWire),
+        card!(game.uuids(), RainbowAura),
+        basic!(game.uuids(), PumpkinSpice),
+    };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
 
     // PumpkinSpice
     // No effects currently apply
@@ -295,9 +309,11 @@ pub fn test_unicorn_phoenix() {
         let mut game = Game::new();
         let uuid1 = game.ez_new_player("Tester");
 
-        let hand = game.p_mut(uuid1).hand_mut();
-        hand.add_card(card!(UnicornPhoenix));
-        hand.add_card(card!(BarbedWire));
+        let cards: Vec<Box<dyn Card>> = vec!{
+            card!(game.uuids(), UnicornPhoenix),
+            card!(game.uuids(), BarbedWire),
+        };
+        game.p_mut(uuid1).hand_mut().add_all(cards);
 
         assert_eq!(game.p_play_id(uuid1, UnicornPhoenix).unwrap(), ());
 
@@ -313,10 +329,12 @@ pub fn test_unicorn_phoenix() {
         let mut game = Game::new();
         let uuid1 = game.ez_new_player("Tester");
 
-        let hand = game.p_mut(uuid1).hand_mut();
-        hand.add_card(card!(UnicornPhoenix));
-        hand.add_card(card!(RainbowAura));
-        hand.add_card(card!(GinormousUnicorn));
+        let cards: Vec<Box<dyn Card>> = vec!{
+            card!(game.uuids(), UnicornPhoenix),
+            card!(game.uuids(), RainbowAura),
+            card!(game.uuids(), GinormousUnicorn),
+        };
+        game.p_mut(uuid1).hand_mut().add_all(cards);
 
         assert_eq!(game.p_play_id(uuid1, UnicornPhoenix).unwrap(), ());
         assert_eq!(game.table.discard.ids(), [GinormousUnicorn]);
@@ -336,9 +354,11 @@ pub fn test_unicorn_phoenix() {
         let mut game = Game::new();
         let uuid1 = game.ez_new_player("Tester");
 
-        let hand = game.p_mut(uuid1).hand_mut();
-        hand.add_card(card!(UnicornPhoenix));
-        hand.add_card(card!(GinormousUnicorn));
+        let cards: Vec<Box<dyn Card>> = vec!{
+            card!(game.uuids(), UnicornPhoenix),
+            card!(game.uuids(), GinormousUnicorn),
+        };
+        game.p_mut(uuid1).hand_mut().add_all(cards);
 
         assert_eq!(game.p_play_id(uuid1, UnicornPhoenix).unwrap(), ());
         assert_eq_ids!(game.table.discard.ids(), vec!{ GinormousUnicorn });
@@ -350,6 +370,23 @@ pub fn test_unicorn_phoenix() {
         assert!(game.p(uuid1).stable().is_empty());
         assert_eq_ids!(game.table.discard.ids(), vec!{ GinormousUnicorn, UnicornPhoenix });
     }
+
+    // It may not be played as the only card in the hand, because there would be nothing to discard
+    {
+        let mut game = Game::new();
+        let uuid1 = game.ez_new_player("Tester");
+
+        let card = card!(game.uuids(), UnicornPhoenix);
+        game.p_mut(uuid1).hand_mut().add_card(card);
+
+        let phoenix = game.p(uuid1).hand().get_id_card(UnicornPhoenix).unwrap();
+        assert!(!game.p_may_play(uuid1, &**phoenix).unwrap());
+
+        // So the player doesn't even try to play it in their turn
+        assert_eq!(game.run_turn().unwrap(), ());
+        assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ UnicornPhoenix });
+        assert!(game.p(uuid1).stable().is_empty());
+    }
 }
 
 #[test]
@@ -359,16 +396,20 @@ pub fn test_tiny_stable() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    let stable = game.p_mut(uuid1).stable_mut();
-    stable.add_card(basic!(PoppedCollars));
-    stable.add_card(basic!(VinylRecords));
-    stable.add_card(basic!(Dancer));
+    let cards: Vec<Box<dyn Card>> = vec!{
+        basic!(game.uuids(), PoppedCollars),
+        basic!(game.uuids(), VinylRecords),
+        basic!(game.uuids(), Dancer),
+    };
+    game.p_mut(uuid1).stable_mut().add_all(cards);
 
-    let hand = game.p_mut(uuid1).hand_mut();
-    hand.add_card(basic!(Emoji));
-    hand.add_card(basic!(Beards));
-    hand.add_card(basic!(Hashtags));
-    hand.add_card(card!(TinyStable));
+    let cards: Vec<Box<dyn Card>> = vec!{
+        basic!(game.uuids(), Emoji),
+        basic!(game.uuids(), Beards),
+        basic!(game.uuids(), Hashtags),
+        card!(game.uuids(), TinyStable),
+    };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
 
     assert_eq!(game.p_play_id(uuid1, TinyStable).unwrap(), ());
     assert_eq!(game.p_play_id(uuid1, Basic).unwrap(), ()); // Emoji, 4
@@ -379,6 +420,40 @@ pub fn test_tiny_stable() {
     assert_eq_ids!(game.table.discard.ids(), vec!{ Basic });
 }
 
+#[test]
+pub fn test_tiny_stable_same_target() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), TinyStable);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    for _ in 0..5 {
+        let card = basic!(game.uuids(), Dancer);
+        game.p_mut(uuid1).stable_mut().add_card(card);
+    }
+    let card = card!(game.uuids(), SeductiveUnicorn);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Narwhal);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    let sacrificed_uuid = game.p(uuid1).stable().filter_uuids(is_unicorn)[0];
+
+    // The Tiny Stable triggers for the Seductive Unicorn and again for the stolen unicorn, both times choosing
+    // the same unicorn to sacrifice, so the second sacrifice fizzles instead of stopping the whole play
+    assert_eq!(game.p_play_id(uuid1, SeductiveUnicorn).unwrap(), ());
+
+    assert_eq!(game.table.discard.uuids(), [sacrificed_uuid]);
+    assert_eq!(game.p(uuid1).stable().len(), 7);
+    assert!(game.p(uuid2).stable().is_empty());
+    assert!(game.log().events().contains(&Event::Action(Action::Fizzle {
+        card_uuid: sacrificed_uuid,
+        location: Location { player_uuid: uuid1, destination: Destination::Stable },
+    })));
+}
+
 #[test]
 pub fn test_good_deal() {
     use CardID::*;
@@ -386,10 +461,12 @@ pub fn test_good_deal() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(GoodDeal));
+    let card = card!(game.uuids(), GoodDeal);
+    game.p_mut(uuid1).hand_mut().add_card(card);
 
     for _ in 0..3 {
-        game.table.drawpile.add_card(card!(BrokenStable));
+        let card = card!(game.uuids(), BrokenStable);
+        game.table.drawpile.add_card(card);
     }
 
     assert_eq!(game.p_play_id(uuid1, GoodDeal).unwrap(), ());
@@ -406,8 +483,10 @@ pub fn test_blatant_thievery() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(BlatantThievery));
-    game.p_mut(uuid2).hand_mut().add_card(card!(ShakeUp));
+    let card = card!(game.uuids(), BlatantThievery);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), ShakeUp);
+    game.p_mut(uuid2).hand_mut().add_card(card);
 
     // Player 1 steals from player 2
     assert_eq!(game.p_play_id(uuid1, BlatantThievery).unwrap(), ());
@@ -424,8 +503,10 @@ pub fn test_targeted_destruction() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(TargetedDestruction));
-    game.p_mut(uuid1).stable_mut().add_card(card!(Slowdown));
+    let card = card!(game.uuids(), TargetedDestruction);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Slowdown);
+    game.p_mut(uuid1).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, TargetedDestruction).unwrap(), ());
 
@@ -443,8 +524,10 @@ pub fn test_unicorn_lasso() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(UnicornLasso));
-    game.p_mut(uuid2).stable_mut().add_card(basic!(PumpkinSpice));
+    let card = card!(game.uuids(), UnicornLasso);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid2).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, UnicornLasso).unwrap(), ());
 
@@ -471,11 +554,13 @@ pub fn test_seductive_unicorn() {
     let uuid2 = game.ez_new_player("Tester");
     let uuid3 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).stable_mut().add_card(baby!(White));
-    game.p_mut(uuid2).stable_mut().add_card(basic!(PumpkinSpice));
+    let card = baby!(game.uuids(), White);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid2).stable_mut().add_card(card);
 
-    let player1 = game.p_mut(uuid1);
-    player1.hand_mut().add_card(card!(SeductiveUnicorn));
+    let card = card!(game.uuids(), SeductiveUnicorn);
+    game.p_mut(uuid1).hand_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, SeductiveUnicorn).unwrap(), ());
 
@@ -502,8 +587,10 @@ pub fn test_unicorn_poison() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(UnicornPoison));
-    game.p_mut(uuid2).stable_mut().add_card(baby!(Yellow));
+    let card = card!(game.uuids(), UnicornPoison);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = baby!(game.uuids(), Yellow);
+    game.p_mut(uuid2).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, UnicornPoison).unwrap(), ());
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ });
@@ -512,6 +599,38 @@ pub fn test_unicorn_poison() {
     assert_eq_ids!(game.table.nursery.ids(), vec!{ Baby });
 }
 
+#[test]
+pub fn test_rainbow_aura() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), UnicornPoison);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let cards: Vec<Box<dyn Card>> = vec!{
+        card!(game.uuids(), RainbowAura),
+        basic!(game.uuids(), Narwhal),
+    };
+    game.p_mut(uuid2).stable_mut().add_all(cards);
+
+    // The only unicorn is protected, so there's nothing to poison
+    let poison_uuid = game.p(uuid1).hand().uuids()[0];
+    let poison = game.p(uuid1).hand().get_card(poison_uuid).unwrap().clone();
+    assert!(!game.p_may_play(uuid1, &*poison).unwrap());
+
+    // Destroying it anyway fizzles
+    let basic_uuid = game.p(uuid2).stable().filter_uuids(is_unicorn)[0];
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(basic_uuid, uuid2, Stable => Discard) }).unwrap(), ());
+    assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ RainbowAura, Basic });
+
+    // But it can still be sacrificed
+    assert_eq!(game.p_sacrifice(uuid2, basic_uuid).unwrap(), ());
+    assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ RainbowAura });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ Basic });
+}
+
 #[test]
 pub fn test_two_for_one() {
     use CardID::*;
@@ -520,15 +639,28 @@ pub fn test_two_for_one() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(TwoForOne));
-    game.p_mut(uuid1).stable_mut().add_card(card!(TinyStable));
-    game.p_mut(uuid2).stable_mut().add_card(card!(BarbedWire));
-    game.p_mut(uuid2).stable_mut().add_card(card!(BrokenStable));
+    let card = card!(game.uuids(), TwoForOne);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), TinyStable);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), BarbedWire);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    let card = card!(game.uuids(), BrokenStable);
+    game.p_mut(uuid2).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, TwoForOne).unwrap(), ());
 
     assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ });
     assert_eq_ids!(game.table.discard.ids(), vec!{ TwoForOne, TinyStable, BarbedWire, BrokenStable });
+
+    // The second destroy step knows which card the first one destroyed
+    let destroyed: Vec<Vec<CardUuid>> = game.log().events().iter().filter_map(|event| match event {
+        Event::Action(Action::Continue { continuation, .. }) => Some(continuation.card_uuids.clone()),
+        _ => None,
+    }).collect();
+    assert_eq!(destroyed.len(), 2);
+    assert!(destroyed[0].is_empty());
+    assert_eq!(destroyed[1].len(), 1);
 }
 
 #[test]
@@ -539,9 +671,12 @@ pub fn test_glitter_bob() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(GlitterBomb));
-    game.p_mut(uuid1).stable_mut().add_card(card!(Slowdown));
-    game.p_mut(uuid2).stable_mut().add_card(card!(GinormousUnicorn));
+    let card = card!(game.uuids(), GlitterBomb);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Slowdown);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), GinormousUnicorn);
+    game.p_mut(uuid2).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, GlitterBomb).unwrap(), ());
 
@@ -555,6 +690,46 @@ pub fn test_glitter_bob() {
     assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ });
 }
 
+/// Decider that makes sure every option is only given once and then picks the last one
+#[derive(Debug)]
+struct UniqueLastChoiceDecider;
+
+impl Decider for UniqueLastChoiceDecider {
+    fn choose(&mut self, _question: &'static str, options: &[u64]) -> usize {
+        assert!(options.iter().enumerate().all(|(i, option)| !options[..i].contains(option)), "Option given twice");
+        options.len() - 1
+    }
+
+    fn choose_n(&mut self, _question: &'static str, options: &[u64], n: usize) -> Vec<usize> { (options.len() - n..options.len()).collect() }
+
+    fn choose_bool(&mut self, _question: &'static str) -> bool { true }
+}
+
+#[test]
+pub fn test_glitter_bomb_sacrifices_itself() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    game.set_decider(uuid1, Box::new(UniqueLastChoiceDecider));
+
+    let card = card!(game.uuids(), Slowdown);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), GlitterBomb);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), GinormousUnicorn);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    // The Glitter Bomb sees itself in its owner's stable while it triggers, and may sacrifice itself
+    assert_eq!(game.bot().unwrap(), ());
+
+    assert_eq_ids!(game.table.discard.ids(), vec!{ GlitterBomb, GinormousUnicorn });
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Slowdown });
+    assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ });
+}
+
 #[test]
 pub fn test_summoning_ritual() {
     use CardID::*;
@@ -562,10 +737,14 @@ pub fn test_summoning_ritual() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(SummoningRitual));
-    game.p_mut(uuid1).hand_mut().add_card(basic!(Beards));
-    game.p_mut(uuid1).hand_mut().add_card(basic!(Emoji));
-    game.p_mut(uuid1).hand_mut().add_card(basic!(PumpkinSpice));
+    let card = card!(game.uuids(), SummoningRitual);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Beards);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Emoji);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid1).hand_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, SummoningRitual).unwrap(), ());
 
@@ -596,15 +775,17 @@ pub fn test_extra_tail() {
     // We depend on the order of the drawpile for testing
     game.table.drawpile.auto_shuffle = false;
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(ExtraTail));
+    let card = card!(game.uuids(), ExtraTail);
+    game.p_mut(uuid1).hand_mut().add_card(card);
 
     // May not be played
-    assert_eq!(game.p_play_id(uuid1, ExtraTail).unwrap_err(), "game#p_play: Player may not play card");
+    assert_eq!(game.p_play_id(uuid1, ExtraTail).unwrap_err(), GameError::NotAllowed { reason: "Player may not play card" });
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ });
     assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ ExtraTail });
 
     // Now it may be played
-    game.p_mut(uuid1).stable_mut().add_card(basic!(PumpkinSpice));
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid1).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, ExtraTail).unwrap(), ());
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Basic, ExtraTail });
@@ -618,16 +799,20 @@ pub fn test_extra_tail() {
     assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{  });
 
     // Make sure there is a cards to draw
-    game.table.drawpile.add_card(card!(TinyStable));
+    let card = card!(game.uuids(), TinyStable);
+    game.table.drawpile.add_card(card);
 
     // User should draw the highest amount of cards allowed and possible (!)
     assert_eq!(game.draw().unwrap(), ());
     assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ TinyStable });
 
     // Make sure there is a cards to draw
-    game.table.drawpile.add_card(card!(TwoForOne)); // (1)
-    game.table.drawpile.add_card(card!(UnicornLasso)); // (2)
-    game.table.drawpile.add_card(card!(BarbedWire)); // (3)
+    let card = card!(game.uuids(), TwoForOne); // (1)
+    game.table.drawpile.add_card(card);
+    let card = card!(game.uuids(), UnicornLasso); // (2)
+    game.table.drawpile.add_card(card);
+    let card = card!(game.uuids(), BarbedWire); // (3)
+    game.table.drawpile.add_card(card);
 
     // User should draw the highest amount of cards allowed and possible (!)
     assert_eq!(game.draw().unwrap(), ());
@@ -643,17 +828,21 @@ pub fn test_rainbow_mane() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(RainbowMane));
-    game.p_mut(uuid1).hand_mut().add_card(card!(Slowdown));
-    game.table.drawpile.add_card(basic!(PoppedCollars));
+    let card = card!(game.uuids(), RainbowMane);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Slowdown);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), PoppedCollars);
+    game.table.drawpile.add_card(card);
 
     // May not be played
-    assert_eq!(game.p_play_id(uuid1, RainbowMane).unwrap_err(), "game#p_play: Player may not play card");
+    assert_eq!(game.p_play_id(uuid1, RainbowMane).unwrap_err(), GameError::NotAllowed { reason: "Player may not play card" });
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ });
     assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ RainbowMane, Slowdown });
 
     // Now it may be played
-    game.p_mut(uuid1).stable_mut().add_card(basic!(PumpkinSpice));
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid1).stable_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, RainbowMane).unwrap(), ());
     assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Basic, RainbowMane });
@@ -683,13 +872,20 @@ pub fn test_reset_button() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(ResetButton)); // card to play -> drawpile
-    game.p_mut(uuid1).hand_mut().add_card(card!(Slowdown)); // downgrade in hand - ignore
-    game.p_mut(uuid1).stable_mut().add_card(card!(BarbedWire)); // downgrade in stable -> drawpile
-    game.p_mut(uuid1).stable_mut().add_card(card!(ExtraTail)); // upgrade in stable -> drawpile
-    game.p_mut(uuid1).stable_mut().add_card(basic!(PumpkinSpice)); // unicorn in stable - ignore
-    game.p_mut(uuid2).stable_mut().add_card(card!(TinyStable)); // downgrade in other stable -> drawpile
-    game.table.discard.add_card(card!(GinormousUnicorn)); // any card in discard -> drawpile
+    let card = card!(game.uuids(), ResetButton); // card to play -> drawpile
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Slowdown); // downgrade in hand - ignore
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), BarbedWire); // downgrade in stable -> drawpile
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), ExtraTail); // upgrade in stable -> drawpile
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = basic!(game.uuids(), PumpkinSpice); // unicorn in stable - ignore
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), TinyStable); // downgrade in other stable -> drawpile
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    let card = card!(game.uuids(), GinormousUnicorn); // any card in discard -> drawpile
+    game.table.discard.add_card(card);
 
     // Play it
     assert_eq!(game.p_play_id(uuid1, ResetButton).unwrap(), ());
@@ -709,13 +905,424 @@ pub fn tests_unfair_bargain() {
     let uuid1 = game.ez_new_player("Tester");
     let uuid2 = game.ez_new_player("Tester");
 
-    game.p_mut(uuid1).hand_mut().add_card(card!(UnfairBargain));
-    game.p_mut(uuid1).hand_mut().add_card(card!(TinyStable));
-    game.p_mut(uuid2).hand_mut().add_card(card!(RainbowMane));
+    let card = card!(game.uuids(), UnfairBargain);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), TinyStable);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), RainbowMane);
+    game.p_mut(uuid2).hand_mut().add_card(card);
 
     assert_eq!(game.p_play_id(uuid1, UnfairBargain).unwrap(), ());
 
     assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ RainbowMane });
     assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ TinyStable });
     assert_eq_ids!(game.table.discard.ids(), vec!{ UnfairBargain });
-}
\ No newline at end of file
+}
+
+/// Decider that always picks the last option(s) and answers no to every question
+#[derive(Debug)]
+struct LastChoiceDecider;
+
+impl Decider for LastChoiceDecider {
+    fn choose(&mut self, _question: &'static str, options: &[u64]) -> usize { options.len() - 1 }
+
+    fn choose_n(&mut self, _question: &'static str, options: &[u64], n: usize) -> Vec<usize> { (options.len() - n..options.len()).collect() }
+
+    fn choose_bool(&mut self, _question: &'static str) -> bool { false }
+}
+
+#[test]
+pub fn test_trigger_order() {
+    /// Play a unicorn into a full stable with a Barbed Wire and a Tiny Stable, while
+    /// player 2 still has to discard a card, and give back why cards moved
+    fn play(decider: Box<dyn Decider>) -> Vec<Reason> {
+        let mut game = Game::new();
+        let uuid1 = game.ez_new_player("Tester");
+        let uuid2 = game.ez_new_player("Tester");
+
+        game.set_decider(uuid1, decider);
+
+        let cards: Vec<Box<dyn Card>> = vec!{ card!(game.uuids(), BarbedWire), card!(game.uuids(), TinyStable) };
+        game.p_mut(uuid1).stable_mut().add_all(cards);
+        for _ in 0..5 {
+            let card = basic!(game.uuids(), Dancer);
+            game.p_mut(uuid1).stable_mut().add_card(card);
+        }
+        let cards: Vec<Box<dyn Card>> = vec!{ basic!(game.uuids(), Dancer), card!(game.uuids(), ShakeUp), card!(game.uuids(), RainbowAura) };
+        game.p_mut(uuid1).hand_mut().add_all(cards);
+        let card = card!(game.uuids(), Neigh);
+        game.p_mut(uuid2).hand_mut().add_card(card);
+
+        // Player 1 plays the unicorn, the Neigh of player 2 is discarded after that
+        let card_uuid = game.p(uuid1).hand().uuids()[0];
+        let neigh_uuid = game.p(uuid2).hand().uuids()[0];
+        let start = game.log().len();
+
+        assert_eq!(game.apply_actions(vec!{
+            delta_same_player!(card_uuid, uuid1, Hand => Stable),
+            delta_same_player!(neigh_uuid, uuid2, Hand => Discard),
+        }).unwrap(), ());
+
+        let moves: Vec<Delta> = game.log().events()[start..].iter().filter_map(|event| match event {
+            Event::Action(Action::Move(delta)) => Some(delta.clone()),
+            _ => None,
+        }).collect();
+
+        // The Neigh is only discarded after everything the unicorn triggered
+        assert_eq!(moves.last().unwrap().card_uuid, neigh_uuid);
+
+        moves.into_iter().map(|delta| delta.reason).collect()
+    }
+
+    use Reason::*;
+
+    // By default the triggers resolve in stable order: the Barbed Wire discards a card and the Tiny Stable
+    // sacrifices a unicorn, which triggers the Barbed Wire again before the Neigh is discarded
+    assert_eq!(play(Box::new(FirstChoiceDecider)), vec!{ Play, Discard, Sacrifice, Discard, Discard });
+
+    // The player may choose to resolve the Tiny Stable first (and then discards the other card)
+    let answers = vec!{ Answer::Choose(0), Answer::Choose(0), Answer::Choose(1), Answer::Choose(1) };
+    assert_eq!(play(Box::new(ScriptedDecider::new(answers))), vec!{ Play, Sacrifice, Discard, Discard, Discard });
+}
+
+#[test]
+pub fn test_follow_up_order() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    let cards: Vec<Box<dyn Card>> = vec!{ card!(game.uuids(), SummoningRitual), card!(game.uuids(), SadisticRitual), basic!(game.uuids(), Dancer) };
+    game.p_mut(uuid1).stable_mut().add_all(cards);
+    let cards: Vec<Box<dyn Card>> = vec!{ basic!(game.uuids(), Narwhal), basic!(game.uuids(), Narwhal) };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
+    let card = card!(game.uuids(), RainbowAura);
+    game.table.drawpile.add_card(card);
+
+    let start = game.log().len();
+
+    // The Summoning Ritual is chosen to resolve first, so it revives a unicorn before the Sadistic Ritual does anything
+    assert_eq!(game.bot().unwrap(), ());
+
+    let moves: Vec<(Destination, Destination)> = game.log().events()[start..].iter().filter_map(|event| match event {
+        Event::Action(Action::Move(delta)) => Some((delta.from.destination, delta.to.destination)),
+        _ => None,
+    }).collect();
+
+    use Destination::*;
+    assert_eq!(moves, vec!{ (Hand, Discard), (Hand, Discard), (Discard, Stable), (Stable, Discard), (Drawpile, Hand) });
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ SummoningRitual, SadisticRitual, Basic });
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ RainbowAura });
+}
+
+#[test]
+pub fn test_fizzle() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let cards: Vec<Box<dyn Card>> = vec!{
+        card!(game.uuids(), BarbedWire),
+        card!(game.uuids(), UnicornLasso),
+    };
+    game.p_mut(uuid1).stable_mut().add_all(cards);
+    let card = card!(game.uuids(), SeductiveUnicorn);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+
+    // There is nothing to steal and, after playing the Seductive Unicorn, nothing to discard
+    assert_eq!(game.p_play_id(uuid1, SeductiveUnicorn).unwrap(), ());
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ BarbedWire, UnicornLasso, SeductiveUnicorn });
+    assert!(game.p(uuid2).stable().is_empty());
+
+    let fizzled: Vec<CardID> = game.log().events().iter().filter_map(|event| match event {
+        Event::Action(Action::Fizzle { card_uuid, .. }) => game.p(uuid1).stable().get_card(*card_uuid).map(|card| card.id()),
+        _ => None,
+    }).collect();
+    assert_eq_ids!(fizzled, vec!{ BarbedWire, SeductiveUnicorn });
+
+    // The Unicorn Lasso has nothing to steal either, so it just does nothing
+    let len = game.log().len();
+    assert_eq!(game.bot().unwrap(), ());
+    assert!(!game.log().events()[len..].iter().any(|event| matches!(event, Event::Action(Action::Fizzle { .. }))));
+
+    // A Unicorn Phoenix that is revived after the last cards were discarded has nothing to discard
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), SummoningRitual);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = card!(game.uuids(), UnicornPhoenix);
+    game.table.discard.add_card(card);
+    let cards: Vec<Box<dyn Card>> = vec!{ basic!(game.uuids(), Dancer), basic!(game.uuids(), Narwhal) };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
+
+    assert_eq!(game.bot().unwrap(), ());
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ SummoningRitual, UnicornPhoenix });
+    assert!(game.p(uuid1).hand().is_empty());
+    assert_eq_ids!(game.table.discard.ids(), vec!{ Basic, Basic });
+    assert!(matches!(game.log().events().last(), Some(Event::Action(Action::Fizzle { .. }))));
+
+    // Two Sadistic Rituals both want to sacrifice the same unicorn and draw the last card,
+    // so the second one fizzles instead of getting the game stuck
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    let cards: Vec<Box<dyn Card>> = vec!{ card!(game.uuids(), SadisticRitual), card!(game.uuids(), SadisticRitual), basic!(game.uuids(), Dancer) };
+    game.p_mut(uuid1).stable_mut().add_all(cards);
+    let card = card!(game.uuids(), RainbowAura);
+    game.table.drawpile.add_card(card);
+
+    let start = game.log().len();
+    assert_eq!(game.bot().unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ SadisticRitual, SadisticRitual });
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ RainbowAura });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ Basic });
+
+    let fizzles = game.log().events()[start..].iter().filter(|event| matches!(event, Event::Action(Action::Fizzle { .. }))).count();
+    assert_eq!(fizzles, 2);
+}
+
+#[test]
+pub fn test_decider() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    game.set_decider(uuid1, Box::new(LastChoiceDecider));
+
+    let card = card!(game.uuids(), BlatantThievery);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), ShakeUp);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    let card = card!(game.uuids(), RainbowAura);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+
+    // Player 1 takes the last card instead of the first one
+    assert_eq!(game.p_play_id(uuid1, BlatantThievery).unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ RainbowAura });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ ShakeUp });
+
+    // Player 1 doesn't want to activate the Glitter Bomb
+    let card = card!(game.uuids(), GlitterBomb);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    assert_eq!(game.bot().unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ GlitterBomb });
+    assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ Basic });
+}
+
+/// Decider that answers with options that don't exist
+#[derive(Debug)]
+struct CheatingDecider;
+
+impl Decider for CheatingDecider {
+    fn choose(&mut self, _question: &'static str, options: &[u64]) -> usize { options.len() }
+
+    fn choose_n(&mut self, _question: &'static str, _options: &[u64], n: usize) -> Vec<usize> { vec!{ 0; n } }
+
+    fn choose_bool(&mut self, _question: &'static str) -> bool { true }
+}
+
+#[test]
+pub fn test_invalid_choice() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+    let uuid3 = game.ez_new_player("Tester");
+
+    game.set_decider(uuid1, Box::new(CheatingDecider));
+
+    let card = card!(game.uuids(), BlatantThievery);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), ShakeUp);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+
+    // A choice that isn't one of the options is refused and nothing happens
+    assert_eq!(game.p_play_id(uuid1, BlatantThievery).unwrap_err(), GameError::InvalidChoice { question: "Which user's hand do you want to look at?" });
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ BlatantThievery });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ ShakeUp });
+
+    // Even the Neighs that have been played in the meantime go back to the hands
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid3).hand_mut().add_card(card);
+
+    assert_eq!(game.p_play_id(uuid1, BlatantThievery).unwrap_err(), GameError::InvalidChoice { question: "Which user's hand do you want to look at?" });
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ BlatantThievery });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ ShakeUp, Neigh });
+    assert_eq_ids!(game.p(uuid3).hand().ids(), vec!{ Neigh });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ });
+
+    // The same goes for choosing the same card several times
+    let cards: Vec<Box<dyn Card>> = vec!{ card!(game.uuids(), RainbowAura), card!(game.uuids(), Slowdown) };
+    game.p_mut(uuid1).hand_mut().add_all(cards);
+    game.set_hand_limit(1);
+
+    assert_eq!(game.eot().unwrap_err(), GameError::InvalidChoice { question: "Which cards do you want to discard?" });
+    assert_eq!(game.p(uuid1).hand().len(), 3);
+}
+
+#[test]
+pub fn test_neigh() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), UnicornPoison);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    let card = baby!(game.uuids(), Yellow);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    // Neighs can't be played on their own
+    assert!(game.p_play_id(uuid2, Neigh).is_err());
+
+    // Player 2 stops the Unicorn Poison
+    assert_eq!(game.p_play_id(uuid1, UnicornPoison).unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ Basic });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ });
+    assert_eq_ids!(game.p(uuid2).stable().ids(), vec!{ Baby });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ UnicornPoison, Neigh });
+
+    // Neighs can be Neigh'd themselves
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+
+    assert_eq!(game.p_play_id(uuid1, Basic).unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ });
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Basic });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ UnicornPoison, Neigh, Neigh, Neigh });
+}
+
+#[test]
+pub fn test_super_neigh() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+    let uuid3 = game.ez_new_player("Tester");
+
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = card!(game.uuids(), SuperNeigh);
+    game.p_mut(uuid3).hand_mut().add_card(card);
+
+    // Player 2 can't play Instant cards
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    let card = card!(game.uuids(), Slowdown);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    // The Super Neigh of player 3 can't be stopped by player 1
+    assert_eq!(game.p_play_id(uuid1, Basic).unwrap(), ());
+
+    assert_eq_ids!(game.p(uuid1).hand().ids(), vec!{ Neigh });
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ });
+    assert_eq_ids!(game.p(uuid2).hand().ids(), vec!{ Neigh });
+    assert_eq_ids!(game.p(uuid3).hand().ids(), vec!{ });
+    assert_eq_ids!(game.table.discard.ids(), vec!{ Basic, SuperNeigh });
+}
+
+#[test]
+pub fn test_preview_play() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester 1");
+    let uuid2 = game.ez_new_player("Tester 2");
+
+    let card = card!(game.uuids(), SeductiveUnicorn);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = baby!(game.uuids(), Narwhal);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = basic!(game.uuids(), Narwhal);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    let card = card!(game.uuids(), GinormousUnicorn);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    let card_uuid = game.p(uuid1).hand().uuids()[0];
+    let baby_uuid = game.p(uuid1).stable().uuids()[0];
+    let basic_uuid = game.p(uuid2).stable().uuids()[0];
+
+    // The preview shows the stolen unicorn too
+    let preview = game.preview_play(uuid1, card_uuid, Box::new(FirstChoiceDecider)).unwrap();
+    assert_eq!(preview.actions, vec!{
+        delta!(card_uuid, [uuid1, Hand] => [uuid1, Stable]),
+        delta!(basic_uuid, [uuid2, Stable] => [uuid1, Stable]),
+    });
+    assert_eq_ids!(preview.stables[&uuid1].clone(), vec!{ Baby, SeductiveUnicorn, Basic });
+    assert_eq_ids!(preview.stables[&uuid2].clone(), vec!{ GinormousUnicorn });
+    assert_eq!(preview.hand_sizes[&uuid1], 0);
+    assert!(preview.discard.is_empty());
+    assert_eq!(preview.outcome, None);
+
+    // The choices are made by the given decider, which plays the card in the stable of player 2
+    let preview = game.preview_play(uuid1, card_uuid, Box::new(LastChoiceDecider)).unwrap();
+    assert_eq!(preview.actions, vec!{
+        delta!(card_uuid, [uuid1, Hand] => [uuid2, Stable]),
+        delta!(baby_uuid, [uuid1, Stable] => [uuid2, Stable]),
+    });
+
+    // But nothing really happened
+    assert_eq!(game.p(uuid1).hand().ids(), [SeductiveUnicorn]);
+    assert_eq!(game.p(uuid1).stable().ids(), [Baby]);
+    assert_eq!(game.p(uuid2).stable().len(), 2);
+
+    // Errors are the same as when really playing the card
+    assert_eq!(game.preview_play(uuid2, card_uuid, Box::new(FirstChoiceDecider)).unwrap_err(),
+        GameError::UnknownCard { uuid: card_uuid, location: Location { player_uuid: uuid2, destination: Destination::Hand } });
+
+    // And afterwards the card can still be played for real
+    assert_eq!(game.p_play(uuid1, card_uuid).unwrap(), ());
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ Baby, SeductiveUnicorn, Basic });
+
+    // The preview doesn't tell which cards are on top of the drawpile
+    let card = card!(game.uuids(), GoodDeal);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    for _ in 0..10 {
+        let card = card!(game.uuids(), Neigh);
+        game.table.drawpile.add_card(card);
+    }
+
+    let card_uuid = game.p(uuid1).hand().uuids()[0];
+    let drawpile = game.table.drawpile.uuids();
+
+    let mut drawn: Vec<CardUuid> = (0..10).map(|_| {
+        let preview = game.preview_play(uuid1, card_uuid, Box::new(FirstChoiceDecider)).unwrap();
+
+        preview.actions.iter().find_map(|action| match action {
+            Action::Move(delta) if delta.from.destination == Destination::Drawpile => Some(delta.card_uuid),
+            _ => None,
+        }).unwrap()
+    }).collect();
+    drawn.sort();
+    drawn.dedup();
+
+    assert!(drawn.len() > 1);
+    assert_eq!(game.table.drawpile.uuids(), drawpile);
+}
@@ -1,25 +1,37 @@
 use crate::*;
 
+macro_rules! assert_eq_ids {
+    ($left:expr, $right:expr) => {
+        assert_eq!(id_sort($left), id_sort($right));
+    };
+}
+
+fn id_sort(mut cards: Vec<CardID>) -> Vec<CardID> {
+    cards.sort();
+    cards
+}
+
 #[test]
 pub fn test_count_unicorns_in_stable() {
+    let mut uuids = UuidAllocator::new();
     let mut stable = Stable::new();
 
-    stable.add_card(basic!(Narwhal));
-    stable.add_card(basic!(Narwhal));  // Not actually twice in the game but alright for testing
-	stable.add_card(baby!(White));
-	stable.add_card(baby!(Skeleton));
-    stable.add_card(baby!(Narwhal));
-    stable.add_card(card!(GinormousUnicorn));  // Counts for 2 unicorns
+    stable.add_card(basic!(uuids, Narwhal));
+    stable.add_card(basic!(uuids, Narwhal));  // Not actually twice in the game but alright for testing
+	stable.add_card(baby!(uuids, White));
+	stable.add_card(baby!(uuids, Skeleton));
+    stable.add_card(baby!(uuids, Narwhal));
+    stable.add_card(card!(uuids, GinormousUnicorn));  // Counts for 2 unicorns
 
     // Add non-unicorns just to be sure
-    stable.add_card(card!(BrokenStable));
-    stable.add_card(card!(RainbowAura));
-    stable.add_card(card!(Slowdown));
+    stable.add_card(card!(uuids, BrokenStable));
+    stable.add_card(card!(uuids, RainbowAura));
+    stable.add_card(card!(uuids, Slowdown));
 
     assert_eq!(stable.count_unicorns(), 7);
 
     // Should still be able to add more cards
-    stable.add_card(card!(BrokenStable));
+    stable.add_card(card!(uuids, BrokenStable));
 }
 
 #[test]
@@ -27,21 +39,26 @@ pub fn test_may_play() {
     let mut game = Game::new();
     let uuid1 = game.ez_new_player("Tester");
 
-    let brokenstable = card!(BrokenStable);
+    let brokenstable = card!(game.uuids(), BrokenStable);
     assert!(game.p_may_play(uuid1, &*brokenstable).unwrap());
     game.p_mut(uuid1).stable_mut().add_card(brokenstable);
 
     // Broken Stable prevents Upgrades (like Rainbow Aura) from being played
-    assert!(!game.p_may_play(uuid1, &*card!(RainbowAura)).unwrap());
+    let rainbowaura = card!(game.uuids(), RainbowAura);
+    assert!(!game.p_may_play(uuid1, &*rainbowaura).unwrap());
+
+    // Unknown players may not play anything
+    assert_eq!(game.p_may_play(PlayerUuid(1337), &*rainbowaura).unwrap_err(), GameError::UnknownPlayer(PlayerUuid(1337)));
 }
 
 #[test]
 pub fn test_stable() {
+    let mut uuids = UuidAllocator::new();
     let mut stable = Stable::new();
 
     // Test count and adding a card
     assert_eq!(stable.len(), 0);
-    stable.add_card(card!(RainbowAura));
+    stable.add_card(card!(uuids, RainbowAura));
     assert_eq!(stable.len(), 1);
 
     // Test getting IDs
@@ -52,7 +69,7 @@ pub fn test_stable() {
 
     // Test getting UUIDs
     let uuids = stable.uuids();
-    let uuid: Option<&u64> = uuids.get(0);
+    let uuid: Option<&CardUuid> = uuids.get(0);
     assert!(uuid.is_some());
     let uuid = *uuid.unwrap();
 
@@ -77,44 +94,892 @@ pub fn test_stable() {
 }
 
 #[test]
-pub fn test_next_card() {
+pub fn test_uuid() {
+    let mut uuids = UuidAllocator::new();
+    let card1: Box<dyn Card> = card!(uuids, RainbowAura);
+    let card2: Box<dyn Card> = card!(uuids, RainbowAura);
+
+    // The card IDs should be the same
+    assert_eq!(card1.id(), card2.id());
+
+    // But the hashes shouldn't
+    assert_ne!(card1.uuid(), card2.uuid());
+}
+
+#[test]
+pub fn test_turn_order() {
+    let mut game = Game::new();
+    assert!(game.current_player().is_none());
+
+    let uuid1 = game.ez_new_player("Zoe");
+    let uuid2 = game.ez_new_player("Alice");
+    let uuid3 = game.ez_new_player("Bob");
+
+    // Players are seated in the order they joined
+    assert_eq!(game.seats(), [uuid1, uuid2, uuid3]);
+    assert_eq!(game.player_uuids(), vec!{ uuid1, uuid2, uuid3 });
+    assert_eq!(game.turn(), 1);
+    assert_eq!(game.current_player().unwrap().uuid(), uuid1);
+
+    game.advance_turn();
+    assert_eq!(game.turn(), 2);
+    assert_eq!(game.current_player_uuid(), Some(uuid2));
+
+    game.advance_turn();
+    assert_eq!(game.current_player_uuid(), Some(uuid3));
+
+    // Back to the first seat
+    game.advance_turn();
+    assert_eq!(game.turn(), 4);
+    assert_eq!(game.current_player_uuid(), Some(uuid1));
+
+    // Drawing seats keeps all players at the table
+    game.shuffle_seats();
+    let mut seats = game.seats().to_vec();
+    let mut uuids = vec!{ uuid1, uuid2, uuid3 };
+    seats.sort();
+    uuids.sort();
+    assert_eq!(seats, uuids);
+    assert_eq!(game.current_player_uuid(), Some(game.seats()[0]));
+}
+
+#[test]
+pub fn test_turn_phases_act_on_current_player() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), RainbowAura);
+    game.table.drawpile.add_card(card);
+
+    // It is the turn of the second player, so they are the one drawing
+    game.advance_turn();
+    assert_eq!(game.draw().unwrap(), ());
+
+    assert_eq!(game.p(uuid1).hand().len(), 0);
+    assert_eq!(game.p(uuid2).hand().len(), 1);
+}
+
+#[test]
+pub fn test_phases_run_once() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    for _ in 0..3 {
+        let card = card!(game.uuids(), BrokenStable);
+        game.table.drawpile.add_card(card);
+    }
+
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.bot().unwrap_err(), GameError::WrongPhase { expected: Phase::Beginning, actual: Phase::Draw });
+
+    // The Draw phase can be run by itself, but only once
+    assert_eq!(game.draw().unwrap(), ());
+    assert_eq!(game.draw().unwrap_err(), GameError::NotAllowed { reason: "Phase already ran this turn" });
+    assert_eq!(game.p(uuid1).hand().len(), 1);
+
+    // Stepping over a phase that already ran doesn't run it again
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Action));
+    assert_eq!(game.p(uuid1).hand().len(), 1);
+
+    // Undoing the phase lets it run again
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Draw));
+    assert_eq!(game.draw().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().len(), 1);
+}
+
+#[test]
+pub fn test_run_turn() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), RainbowAura);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    for _ in 0..3 {
+        let card = card!(game.uuids(), BrokenStable);
+        game.table.drawpile.add_card(card);
+    }
+
+    // Without a driven turn, the phases aren't enforced
+    assert_eq!(game.phase(), None);
+
+    // Beginning of turn
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Draw));
+
+    // Cards can't be played outside the Action phase
+    let card_uuid = game.p(uuid1).hand().uuids()[0];
+    assert_eq!(game.p_play(uuid1, card_uuid).unwrap_err(), GameError::WrongPhase { expected: Phase::Action, actual: Phase::Draw });
+    assert_eq!(game.eot().unwrap_err(), GameError::WrongPhase { expected: Phase::End, actual: Phase::Draw });
+
+    // Draw phase
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Action));
+    assert_eq!(game.p(uuid1).hand().len(), 2);
+
+    // Only the current player may do something, and only once
+    assert!(game.p_draw(uuid2).is_err());
+    assert_eq!(game.p_play(uuid1, card_uuid).unwrap(), ());
+    assert!(game.p_draw(uuid1).is_err());
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ CardID::RainbowAura });
+
+    // The Action phase is already done, then End of turn
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::End));
+    assert_eq!(game.step().unwrap(), ());
+
+    // Next player's turn
+    assert_eq!(game.phase(), Some(Phase::Beginning));
+    assert_eq!(game.turn(), 2);
+    assert_eq!(game.current_player_uuid(), Some(uuid2));
+
+    // Player 2 draws a card and plays it in their action phase
+    // (the first choice for a Downgrade is the stable of the first player)
+    assert_eq!(game.run_turn().unwrap(), ());
+    assert_eq!(game.p(uuid2).hand().len(), 0);
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ CardID::RainbowAura, CardID::BrokenStable });
+    assert_eq!(game.current_player_uuid(), Some(uuid1));
+}
+
+#[test]
+pub fn test_win_condition() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    assert_eq!(game.unicorns_to_win(), 7);
+
+    // Ginormous Unicorn counts for two
+    let card = card!(game.uuids(), GinormousUnicorn);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    for _ in 0..4 {
+        let card = basic!(game.uuids(), Dancer);
+        game.p_mut(uuid1).stable_mut().add_card(card);
+    }
+    let card = basic!(game.uuids(), Emoji);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Beards);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+
+    // 7 unicorns after playing the basic unicorn
+    assert!(!game.is_over());
+    assert_eq!(game.p_play_id(uuid1, CardID::Basic).unwrap(), ());
+    assert!(game.is_over());
+
+    let outcome = game.outcome().unwrap();
+    assert_eq!(outcome.winner, uuid1);
+    assert_eq!(outcome.turn, 1);
+    assert_eq!(outcome.stables[&uuid1].len(), 6);
+    assert!(outcome.stables[&uuid2].is_empty());
+
+    // Nothing can happen anymore
+    assert_eq!(game.p_play_id(uuid2, CardID::Basic).unwrap_err(), GameError::GameOver);
+    assert_eq!(game.step().unwrap_err(), GameError::GameOver);
+}
+
+#[test]
+pub fn test_win_in_run_turn() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    game.ez_new_player("Tester");
+
+    for _ in 0..6 {
+        let card = basic!(game.uuids(), Dancer);
+        game.p_mut(uuid1).stable_mut().add_card(card);
+    }
+    let card = basic!(game.uuids(), Emoji);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+
+    // The turn stops as soon as the player wins by playing the basic unicorn
+    assert_eq!(game.run_turn().unwrap(), ());
+    assert!(game.is_over());
+    assert_eq!(game.outcome().unwrap().winner, uuid1);
+    assert_eq!(game.phase(), Some(Phase::Action));
+
+    // Only then nothing can happen anymore
+    assert_eq!(game.run_turn().unwrap_err(), GameError::GameOver);
+}
+
+#[test]
+pub fn test_unicorns_to_win() {
+    let mut game = Game::new();
+    for _ in 0..5 {
+        game.ez_new_player("Tester");
+    }
+    assert_eq!(game.unicorns_to_win(), 7);
+
+    // Less unicorns are needed with six or more players
+    game.ez_new_player("Tester");
+    assert_eq!(game.unicorns_to_win(), 6);
+
+    game.set_unicorns_to_win(3);
+    assert_eq!(game.unicorns_to_win(), 3);
+}
+
+#[test]
+pub fn test_registry() {
+    let mut uuids = UuidAllocator::new();
+    let cards: Vec<Box<dyn Card>> = DeckSpec::Base.babies(&mut uuids).into_iter().chain(DeckSpec::Base.deck(&mut uuids)).collect();
+
+    // Every card of the base game is registered with the right info and count
+    for card in &cards {
+        let info = CardRegistry::get(card.id());
+
+        assert_eq!(info.cardtype, card.cardtype());
+
+        // The text of the themed unicorns depends on their theme
+        if !matches!(card.state(), CardState::Baby(_) | CardState::Basic(_)) {
+            assert_eq!(info.description, card.description());
+        }
+        assert_eq!(info.expansion, Expansion::Base);
+        assert_eq!(info.count, cards.iter().filter(|c| c.id() == card.id()).count());
+
+        // And can be built again
+        let restored = CardRegistry::restore(card.id(), card.uuid(), card.state()).unwrap();
+        assert_eq!(restored.uuid(), card.uuid());
+        assert_eq!(restored.name(), card.name());
+    }
+
+    assert_eq!(CardRegistry::expansion(Expansion::Base).map(|info| info.count).sum::<usize>(), cards.len());
+    assert_eq!(CardRegistry::get(CardID::Neigh).name, "Neigh");
+
+    // Fresh cards of every type can be built, except the themed unicorns
+    let info = CardRegistry::get(CardID::UnicornLasso);
+    assert_eq!(info.build(&mut uuids, CardState::Stateless).unwrap().id(), CardID::UnicornLasso);
+    assert!(CardRegistry::get(CardID::Basic).build(&mut uuids, CardState::Stateless).is_none());
+    assert!(CardRegistry::restore(CardID::Neigh, CardUuid(1337), CardState::Basic(cards::BasicTheme::Dancer)).is_none());
+}
+
+#[test]
+pub fn test_seed() {
+    let play = |seed: u64| {
+        let mut game = Game::setup_with_seed(&["Alice", "Bob", "Carol"], DeckSpec::Base, seed).unwrap();
+        game.shuffle_seats();
+
+        for player_uuid in game.player_uuids() {
+            game.set_decider(player_uuid, Box::new(PassiveDecider));
+        }
+
+        for _ in 0..6 {
+            assert_eq!(game.run_turn().unwrap(), ());
+        }
+
+        game
+    };
+
+    // The same seed gives exactly the same game
+    let game = play(1337);
+    let same = play(1337);
+    assert_eq!(same.seed(), 1337);
+    assert_eq!(same.log(), game.log());
+    assert_eq!(same.seats(), game.seats());
+    assert_eq!(same.table.drawpile.uuids(), game.table.drawpile.uuids());
+
+    for &player_uuid in game.seats() {
+        assert_eq!(same.p(player_uuid).hand().uuids(), game.p(player_uuid).hand().uuids());
+    }
+
+    // And a different seed gives a different one
+    let other = play(1338);
+    assert_ne!(other.seats(), game.seats());
+    assert_ne!(other.table.drawpile.ids(), game.table.drawpile.ids());
+
+    // Even without setting up a game
+    assert_eq!(Game::with_seed(42).ez_new_player("Alice"), Game::with_seed(42).ez_new_player("Bob"));
+}
+
+#[test]
+pub fn test_setup() {
+    let mut uuids = UuidAllocator::new();
+    let deck_size = DeckSpec::Base.deck(&mut uuids).len();
+    assert_eq!(DeckSpec::Base.babies(&mut uuids).len(), 13);
+
+    let game = Game::setup(&["Alice", "Bob", "Carol"], DeckSpec::Base).unwrap();
+
+    assert_eq!(game.player_uuids().len(), 3);
+    assert_eq!(game.table.nursery.len(), 13 - 3);
+    assert_eq!(game.table.drawpile.len(), deck_size - 3 * 5);
+    assert!(game.table.discard.is_empty());
+
+    for player_uuid in game.player_uuids() {
+        assert_eq!(game.p(player_uuid).stable().ids(), [CardID::Baby]);
+        assert_eq!(game.p(player_uuid).hand().len(), 5);
+    }
+
+    // Too few or too many players
+    assert_eq!(Game::setup(&["Alice"], DeckSpec::Base).unwrap_err(), GameError::InvalidPlayerCount(1));
+    assert_eq!(Game::setup(&["Tester"; 9], DeckSpec::Base).unwrap_err(), GameError::InvalidPlayerCount(9));
+}
+
+#[test]
+pub fn test_hand_limit() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    for _ in 0..9 {
+        let card = card!(game.uuids(), Slowdown);
+        game.p_mut(uuid1).hand_mut().add_card(card);
+    }
+    let card = card!(game.uuids(), ExtraTail);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+
+    // Cards can raise the hand limit until the next beginning of turn
+    assert_eq!(game.apply_actions(vec!{ delta_hand_limit!(uuid1 => By(1)) }).unwrap(), ());
+    assert_eq!(game.p(uuid1).hand_limit, 8);
+
+    // Discard down to 8 cards
+    assert_eq!(game.eot().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().len(), 8);
+    assert_eq!(game.table.discard.ids(), [CardID::Slowdown]);
+
+    // Back to the game's hand limit on the beginning of the turn
+    assert_eq!(game.bot().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand_limit, 7);
+
+    assert_eq!(game.eot().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().len(), 7);
+
+    // The hand limit can be changed for the whole game
+    game.set_hand_limit(5);
+    assert_eq!(game.eot().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().len(), 5);
+    assert_eq!(game.table.discard.len(), 4);
+
+    // Cards can lower the limits too, but never below zero
+    let actions = vec!{
+        delta_hand_limit!(uuid1 => To(4)),
+        delta_hand_limit!(uuid1 => By(-2)),
+        delta_draw_number!(uuid1 => To(1)),
+        delta_draw_number!(uuid1 => By(-5)),
+    };
+    assert_eq!(game.apply_actions(actions).unwrap(), ());
+    assert_eq!(game.p(uuid1).hand_limit, 2);
+    assert_eq!(game.p(uuid1).may_draw_number, 0);
+}
+
+#[test]
+pub fn test_errors() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+
+    let card = card!(game.uuids(), ExtraTail);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card_uuid = game.p(uuid1).stable().uuids()[0];
+
+    // Unknown cards and players
+    assert_eq!(game.p_play(uuid1, card_uuid).unwrap_err(), GameError::UnknownCard { uuid: card_uuid, location: Location { player_uuid: uuid1, destination: Destination::Hand } });
+    assert_eq!(game.p_play_id(uuid1, CardID::Neigh).unwrap_err(), GameError::UnknownCardID { id: CardID::Neigh, location: Location { player_uuid: uuid1, destination: Destination::Hand } });
+    assert_eq!(game.p_sacrifice(PlayerUuid(1337), card_uuid).unwrap_err(), GameError::UnknownPlayer(PlayerUuid(1337)));
+
+    // Nothing left to draw
+    assert_eq!(game.p_draw(uuid1).unwrap_err(), GameError::EmptyDrawpile);
+
+    // Illegal deltas are refused without losing the card
+    let delta = Delta {
+        card_uuid,
+        from: Location { player_uuid: uuid1, destination: Destination::Drawpile },
+        to: Location { player_uuid: uuid1, destination: Destination::Discard },
+        reason: Reason::Move,
+    };
+    assert_eq!(game.apply_actions(vec!{ Action::Move(delta.clone()) }).unwrap_err(), GameError::InvalidDelta(delta));
+    assert_eq!(game.apply_actions(vec!{ delta!(card_uuid, [uuid1, Stable] => [PlayerUuid(1337), Hand]) }).unwrap_err(), GameError::UnknownPlayer(PlayerUuid(1337)));
+    assert_eq!(game.p(uuid1).stable().uuids(), [card_uuid]);
+
+    // The errors can be shown to the players
+    assert_eq!(GameError::EmptyDrawpile.to_string(), "There are no more cards to draw");
+    assert_eq!(GameError::NotAllowed { reason: "Player may not play card" }.to_string(), "Not allowed: Player may not play card");
+}
+
+#[test]
+pub fn test_apply_actions_rollback() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester 1");
+    let uuid2 = game.ez_new_player("Tester 2");
+
+    let card = card!(game.uuids(), SeductiveUnicorn);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let card = basic!(game.uuids(), Narwhal);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    let card_uuid = game.p(uuid1).hand().uuids()[0];
+    let action = delta!(card_uuid, [uuid1, Hand] => [uuid1, Stable]);
+
+    // The Seductive Unicorn steals the Basic Unicorn, but the second action fails
+    assert_eq!(game.apply_actions(vec!{ action.clone(), delta_same_player!(CardUuid(1337), uuid1, Hand => Discard) }).unwrap_err(),
+        GameError::UnknownCard { uuid: CardUuid(1337), location: Location { player_uuid: uuid1, destination: Destination::Hand } });
+
+    // So nothing happened at all
+    assert_eq!(game.p(uuid1).hand().ids(), [CardID::SeductiveUnicorn]);
+    assert!(game.p(uuid1).stable().is_empty());
+    assert_eq!(game.p(uuid2).stable().ids(), [CardID::Basic]);
+    assert!(game.table.discard.is_empty());
+
+    // Not even inside the card, so it can still steal like it was never played
+    assert_eq!(game.apply_actions(vec!{ action }).unwrap(), ());
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ CardID::SeductiveUnicorn, CardID::Basic });
+    assert!(game.p(uuid2).stable().is_empty());
+}
+
+#[test]
+pub fn test_delta_reasons() {
     use CardID::*;
 
-    let mut stable = Stable::new();
-    stable.add_card(basic!(Narwhal));
-    stable.add_card(card!(NarwhalTorpedo));
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester 1");
+    let uuid2 = game.ez_new_player("Tester 2");
+
+    fn reason(action: Action) -> Reason {
+        match action {
+            Action::Move(delta) => delta.reason,
+            _ => unreachable!(),
+        }
+    }
 
-    for _ in 0..2 {
-        stable.reset_cards();
+    // Without a reason the usual one is used
+    assert_eq!(reason(delta_same_player!(CardUuid::RANDOM, uuid1, Drawpile => Hand)), Reason::Draw);
+    assert_eq!(reason(delta_same_player!(CardUuid(1), uuid1, Stable => Discard)), Reason::Destroy);
+    assert_eq!(reason(delta_same_player!(CardUuid(1), uuid1, Stable => Discard, Sacrifice)), Reason::Sacrifice);
+    assert_eq!(reason(delta!(CardUuid(1), [uuid2, Stable] => [uuid1, Stable])), Reason::Steal);
 
+    // A stolen baby stays in the hand, a returned one goes back to the nursery
+    let card = baby!(game.uuids(), Red);
+    let baby_uuid = card.uuid();
+    game.p_mut(uuid1).stable_mut().add_card(card);
 
-        let next = stable.next_card();
-        assert!(next.is_some());
-        let next = next.unwrap();
-        assert!(next.id() == Basic);
-        stable.readd_card(next); // push back
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(baby_uuid, uuid1, Stable => Hand, Steal) }).unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().ids(), [Baby]);
+
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(baby_uuid, uuid1, Hand => Stable) }).unwrap(), ());
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(baby_uuid, uuid1, Stable => Hand) }).unwrap(), ());
+    assert!(game.p(uuid1).hand().is_empty());
+    assert_eq!(game.table.nursery.ids(), [Baby]);
+
+    // Sacrifices are remembered as such
+    let card = basic!(game.uuids(), Narwhal);
+    let basic_uuid = card.uuid();
+    game.p_mut(uuid2).stable_mut().add_card(card);
+
+    assert_eq!(game.p_sacrifice(uuid2, basic_uuid).unwrap(), ());
+    assert_eq!(game.log().events().last(), Some(&Event::Action(delta_same_player!(basic_uuid, uuid2, Stable => Discard, Sacrifice))));
+}
 
+#[test]
+pub fn test_undo_redo() {
+    use CardID::*;
 
-        let next = stable.next_card();
-        assert!(next.is_some());
-        let next = next.unwrap();
-        assert!(next.id() == NarwhalTorpedo);
-        stable.readd_card(next); // push back
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester 1");
+    let uuid2 = game.ez_new_player("Tester 2");
 
+    assert_eq!(game.undo().unwrap_err(), GameError::NothingToUndo);
+    assert_eq!(game.redo().unwrap_err(), GameError::NothingToRedo);
 
-        let next = stable.next_card();
-        assert!(next.is_none());
+    let card = card!(game.uuids(), UnicornLasso);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let card = basic!(game.uuids(), PumpkinSpice);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    for _ in 0..3 {
+        let card = card!(game.uuids(), Neigh);
+        game.table.drawpile.add_card(card);
     }
+
+    // The Unicorn Lasso steals a unicorn and remembers it
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Draw));
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ UnicornLasso, Basic });
+
+    // Take back the whole Beginning of Turn phase, including the memory of the lasso
+    assert!(game.can_undo());
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.phase(), None);
+    assert_eq!(game.p(uuid1).stable().ids(), [UnicornLasso]);
+    assert_eq!(game.p(uuid2).stable().ids(), [Basic]);
+
+    // Redo it without having to make the choices again
+    assert!(game.can_redo());
+    assert_eq!(game.redo().unwrap(), ());
+    assert_eq!(game.phase(), Some(Phase::Draw));
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ UnicornLasso, Basic });
+
+    // Random draws are undone and redone with the card that was really drawn
+    assert_eq!(game.step().unwrap(), ());
+    let drawn = game.p(uuid1).hand().uuids();
+    assert_eq!(drawn.len(), 1);
+    assert_eq!(game.table.drawpile.len(), 2);
+
+    assert_eq!(game.undo().unwrap(), ());
+    assert!(game.p(uuid1).hand().is_empty());
+    assert_eq!(game.table.drawpile.len(), 3);
+
+    assert_eq!(game.redo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), drawn);
+    assert!(!game.can_redo());
+
+    // The lasso still knows what to give back at the end of the turn
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.p(uuid1).stable().ids(), [UnicornLasso]);
+    assert_eq!(game.p(uuid2).stable().ids(), [Basic]);
+    assert_eq!(game.current_player_uuid(), Some(uuid2));
+
+    // Doing something new after an undo means there is nothing to redo anymore
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.current_player_uuid(), Some(uuid1));
+    assert_eq_ids!(game.p(uuid1).stable().ids(), vec!{ UnicornLasso, Basic });
+    assert_eq!(game.step().unwrap(), ());
+    assert!(!game.can_redo());
+    assert_eq!(game.p(uuid2).stable().ids(), [Basic]);
 }
 
 #[test]
-pub fn test_uuid() {
-    let card1: Box<dyn Card> = card!(RainbowAura);
-    let card2: Box<dyn Card> = card!(RainbowAura);
+pub fn test_undo_redo_order() {
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
 
-    // The card IDs should be the same
-    assert_eq!(card1.id(), card2.id());
+    for _ in 0..5 {
+        let card = basic!(game.uuids(), Narwhal);
+        game.table.drawpile.add_card(card);
+    }
+    for _ in 0..4 {
+        let card = basic!(game.uuids(), Dancer);
+        game.p_mut(uuid1).hand_mut().add_card(card);
+    }
 
-    // But the hashes shouldn't
-    assert_ne!(card1.uuid(), card2.uuid());
-}
\ No newline at end of file
+    let hand = game.p(uuid1).hand().uuids();
+    let drawpile = game.table.drawpile.uuids();
+
+    // Drawing takes the card from the middle of the drawpile, which is put back exactly there
+    assert_eq!(game.p_draw(uuid1).unwrap(), ());
+    let drawn_hand = game.p(uuid1).hand().uuids();
+    let drawn_drawpile = game.table.drawpile.uuids();
+
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), hand);
+    assert_eq!(game.table.drawpile.uuids(), drawpile);
+
+    assert_eq!(game.redo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), drawn_hand);
+    assert_eq!(game.table.drawpile.uuids(), drawn_drawpile);
+    assert_eq!(game.undo().unwrap(), ());
+
+    // Playing a card takes it from the hand, which gets its old order back on undo
+    assert_eq!(game.p_play(uuid1, hand[0]).unwrap(), ());
+    let played_hand = game.p(uuid1).hand().uuids();
+    assert_eq!(played_hand.len(), 3);
+
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), hand);
+
+    assert_eq!(game.redo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), played_hand);
+    assert_eq!(game.undo().unwrap(), ());
+
+    // Putting a card in the drawpile shuffles it, which is undone and redone as well
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(hand[1], uuid1, Hand => Drawpile) }).unwrap(), ());
+    let shuffled_hand = game.p(uuid1).hand().uuids();
+    let shuffled_drawpile = game.table.drawpile.uuids();
+
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), hand);
+    assert_eq!(game.table.drawpile.uuids(), drawpile);
+
+    assert_eq!(game.redo().unwrap(), ());
+    assert_eq!(game.p(uuid1).hand().uuids(), shuffled_hand);
+    assert_eq!(game.table.drawpile.uuids(), shuffled_drawpile);
+}
+
+/// Decider that never plays a card, to keep random games simple
+#[derive(Debug)]
+struct PassiveDecider;
+
+impl Decider for PassiveDecider {
+    fn choose(&mut self, _question: &'static str, _options: &[u64]) -> usize { 0 }
+
+    fn choose_n(&mut self, _question: &'static str, _options: &[u64], n: usize) -> Vec<usize> { (0..n).collect() }
+
+    fn choose_bool(&mut self, _question: &'static str) -> bool { false }
+}
+
+#[test]
+pub fn test_replay() {
+    let mut game = Game::setup(&["Alice", "Bob", "Carol"], DeckSpec::Base).unwrap();
+    game.shuffle_seats();
+
+    for player_uuid in game.player_uuids() {
+        game.set_decider(player_uuid, Box::new(PassiveDecider));
+    }
+
+    for _ in 0..6 {
+        assert_eq!(game.run_turn().unwrap(), ());
+    }
+
+    // Take back the last phase and do something else
+    assert_eq!(game.step().unwrap(), ());
+    assert_eq!(game.undo().unwrap(), ());
+    let player_uuid = game.current_player_uuid().unwrap();
+    let _ = game.p_draw(player_uuid);
+
+    // Every kind of action can be written and replayed
+    let actions = vec!{
+        delta_draw_number!(player_uuid => By(-1)),
+        delta_hand_limit!(player_uuid => To(3)),
+        delta_reveal!([game.seats()[0], Hand] => player_uuid),
+        Action::Shuffle,
+    };
+    assert_eq!(game.apply_actions(actions).unwrap(), ());
+
+    // A failed command leaves nothing but itself in the log, since nothing it did really happened
+    let len = game.log().len();
+    let actions = vec!{
+        delta_hand_limit!(player_uuid => To(5)),
+        delta_same_player!(CardUuid(1337), player_uuid, Hand => Discard),
+    };
+    assert!(game.apply_actions(actions).is_err());
+    assert_eq!(game.log().len(), len + 1);
+    assert!(matches!(game.log().events().last(), Some(Event::Apply(_))));
+
+    // The log survives being written and read again
+    let mut file = vec!{};
+    assert!(game.log().write_to(&mut file).is_ok());
+    let log = EventLog::read_from(&mut &file[..]).unwrap();
+    assert_eq!(&log, game.log());
+    assert!(matches!(log.events()[0], Event::Setup { .. }));
+
+    // Replaying it gives the same game
+    let replay = Game::replay(&log).unwrap();
+    assert_eq!(replay.log().len(), log.len());
+    assert_eq!(replay.turn(), game.turn());
+    assert_eq!(replay.phase(), game.phase());
+    assert_eq!(replay.table.discard.ids(), game.table.discard.ids());
+    assert_eq!(replay.table.drawpile.len(), game.table.drawpile.len());
+
+    for (replay_uuid, game_uuid) in replay.seats().iter().zip(game.seats()) {
+        assert_eq!(replay.p(*replay_uuid).name(), game.p(*game_uuid).name());
+        assert_eq_ids!(replay.p(*replay_uuid).hand().ids(), game.p(*game_uuid).hand().ids());
+        assert_eq_ids!(replay.p(*replay_uuid).stable().ids(), game.p(*game_uuid).stable().ids());
+    }
+
+    // Also through a file
+    let path = ::std::env::temp_dir().join(format!("unsafe_unicorns_test_replay_{}.log", ::std::process::id()));
+    assert!(game.log().save(&path).is_ok());
+    assert_eq!(&EventLog::load(&path).unwrap(), game.log());
+    let _ = ::std::fs::remove_file(&path);
+
+    // A log that doesn't fit with what happens can't be replayed
+    let mut broken = log.clone();
+    broken.push(Event::Turn { turn: 1337, player_uuid });
+    assert_eq!(Game::replay(&broken).unwrap_err(), GameError::InvalidReplay { index: log.len() });
+    assert_eq!(Game::replay(&EventLog::new()).unwrap_err(), GameError::InvalidReplay { index: 0 });
+}
+
+/// Everything an observer can be told about
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Observed {
+    TurnStart(u64, PlayerUuid),
+    TurnEnd(u64, PlayerUuid),
+    Phase(Phase),
+    Played(CardUuid),
+    Moved(Action),
+    Trigger(CardUuid),
+    Fizzle(CardUuid),
+    Win(PlayerUuid),
+    Error(GameError),
+}
+
+/// Observer that remembers everything it's told in a list that's shared with the test
+#[derive(Debug, Default, Clone)]
+struct RecordingObserver(::std::rc::Rc<::std::cell::RefCell<Vec<Observed>>>);
+
+impl RecordingObserver {
+    fn take(&self) -> Vec<Observed> {
+        self.0.borrow_mut().drain(..).collect()
+    }
+}
+
+impl GameObserver for RecordingObserver {
+    fn on_turn_start(&mut self, _game: &Game, turn: u64, player_uuid: PlayerUuid) { self.0.borrow_mut().push(Observed::TurnStart(turn, player_uuid)); }
+    fn on_turn_end(&mut self, _game: &Game, turn: u64, player_uuid: PlayerUuid) { self.0.borrow_mut().push(Observed::TurnEnd(turn, player_uuid)); }
+    fn on_phase(&mut self, _game: &Game, phase: Phase) { self.0.borrow_mut().push(Observed::Phase(phase)); }
+    fn on_card_played(&mut self, _game: &Game, _player_uuid: PlayerUuid, card_uuid: CardUuid) { self.0.borrow_mut().push(Observed::Played(card_uuid)); }
+    fn on_card_moved(&mut self, _game: &Game, delta: &Delta) { self.0.borrow_mut().push(Observed::Moved(Action::Move(delta.clone()))); }
+    fn on_trigger(&mut self, _game: &Game, card_uuid: CardUuid, _actions: &[Action]) { self.0.borrow_mut().push(Observed::Trigger(card_uuid)); }
+    fn on_fizzle(&mut self, _game: &Game, card_uuid: CardUuid, _location: &Location) { self.0.borrow_mut().push(Observed::Fizzle(card_uuid)); }
+    fn on_win(&mut self, _game: &Game, outcome: &GameOutcome) { self.0.borrow_mut().push(Observed::Win(outcome.winner)); }
+    fn on_error(&mut self, _game: &Game, error: &GameError) { self.0.borrow_mut().push(Observed::Error(error.clone())); }
+}
+
+#[test]
+pub fn test_observer() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Tester");
+    let uuid2 = game.ez_new_player("Tester");
+
+    let observer = RecordingObserver::default();
+    game.add_observer(Box::new(observer.clone()));
+    game.add_observer(Box::new(LoggingObserver));
+
+    let card = card!(game.uuids(), BarbedWire);
+    game.p_mut(uuid1).stable_mut().add_card(card);
+    let barbed_uuid = game.p(uuid1).stable().uuids()[0];
+    let card = basic!(game.uuids(), Dancer);
+    game.table.drawpile.add_card(card);
+
+    // A whole turn in which player 1 draws a basic unicorn and plays it, which triggers the Barbed Wire
+    assert_eq!(game.run_turn().unwrap(), ());
+
+    let card_uuid = game.p(uuid1).stable().uuids()[1];
+    assert_eq!(observer.take(), vec!{
+        Observed::TurnStart(1, uuid1),
+        Observed::Phase(Phase::Beginning),
+        Observed::Phase(Phase::Draw),
+        Observed::Moved(delta_same_player!(card_uuid, uuid1, Drawpile => Hand)),
+        Observed::Phase(Phase::Action),
+        Observed::Played(card_uuid),
+        Observed::Moved(delta_same_player!(card_uuid, uuid1, Hand => Stable)),
+        Observed::Trigger(barbed_uuid),
+        Observed::Fizzle(barbed_uuid),
+        Observed::Phase(Phase::End),
+        Observed::TurnEnd(1, uuid1),
+    });
+
+    // Errors are told after rolling back
+    let card = card!(game.uuids(), Neigh);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    assert!(game.p_play_id(uuid2, Neigh).is_err());
+    assert_eq!(observer.take(), vec!{ Observed::Error(GameError::WrongPhase { expected: Phase::Action, actual: Phase::Beginning }) });
+
+    // And so are winners
+    game.set_unicorns_to_win(1);
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid2).hand_mut().add_card(card);
+    let card_uuid = game.p(uuid2).hand().uuids()[1];
+    assert_eq!(game.apply_actions(vec!{ delta_same_player!(card_uuid, uuid2, Hand => Stable) }).unwrap(), ());
+    assert_eq!(observer.take().last(), Some(&Observed::Win(uuid2)));
+}
+
+#[test]
+pub fn test_view_for() {
+    use CardID::*;
+
+    let mut game = Game::new();
+    let uuid1 = game.ez_new_player("Alice");
+    let uuid2 = game.ez_new_player("Bob");
+
+    let card = card!(game.uuids(), BlatantThievery);
+    game.p_mut(uuid1).hand_mut().add_card(card);
+    let cards: Vec<Box<dyn Card>> = vec!{ card!(game.uuids(), Slowdown), card!(game.uuids(), ShakeUp) };
+    game.p_mut(uuid2).hand_mut().add_all(cards);
+    let card = basic!(game.uuids(), Dancer);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    let card = card!(game.uuids(), RainbowAura);
+    game.table.drawpile.add_card(card);
+
+    // Everybody sees the stables and their own hand, but only the size of the other hands
+    let view = game.view_for(uuid1).unwrap();
+    assert_eq!(view.player_uuid, uuid1);
+    assert_eq!(view.hand().iter().map(|card| card.id).collect::<Vec<_>>(), [BlatantThievery]);
+    assert_eq!(view.player(uuid2).unwrap().name, "Bob");
+    assert_eq!(view.player(uuid2).unwrap().stable.iter().map(|card| card.id).collect::<Vec<_>>(), [Basic]);
+    assert_eq!(view.player(uuid2).unwrap().hand_size, 2);
+    assert_eq!(view.player(uuid2).unwrap().hand, None);
+    assert_eq!(view.drawpile_size, 1);
+    assert_eq!(view.nursery_size, 0);
+    assert!(view.discard.is_empty());
+
+    assert_eq!(game.view_for(uuid2).unwrap().player(uuid1).unwrap().hand, None);
+    assert_eq!(game.view_for(PlayerUuid(1337)).unwrap_err(), GameError::UnknownPlayer(PlayerUuid(1337)));
+
+    // The Blatant Thievery reveals the hand of player 2 to player 1 until the end of the turn
+    assert_eq!(game.p_play_id(uuid1, BlatantThievery).unwrap(), ());
+
+    let view = game.view_for(uuid1).unwrap();
+    assert_eq!(view.player(uuid2).unwrap().hand.as_ref().unwrap().iter().map(|card| card.id).collect::<Vec<_>>(), game.p(uuid2).hand().ids());
+    assert_eq!(view.hand().len(), 1);
+    assert_eq!(view.discard.iter().map(|card| card.id).collect::<Vec<_>>(), [BlatantThievery]);
+    assert_eq!(game.view_for(uuid2).unwrap().player(uuid1).unwrap().hand, None);
+
+    // Undoing the card hides the hand again
+    assert_eq!(game.undo().unwrap(), ());
+    assert_eq!(game.view_for(uuid1).unwrap().player(uuid2).unwrap().hand, None);
+    assert_eq!(game.redo().unwrap(), ());
+    assert!(game.view_for(uuid1).unwrap().player(uuid2).unwrap().hand.is_some());
+
+    game.advance_turn();
+    assert_eq!(game.view_for(uuid1).unwrap().player(uuid2).unwrap().hand, None);
+
+    // A reveal is rolled back together with the rest of the failed actions
+    let hand2 = Location { player_uuid: uuid2, destination: Destination::Hand };
+    assert!(game.apply_actions(vec!{ delta_reveal!([uuid2, Hand] => uuid1), delta_same_player!(CardUuid(1337), uuid1, Hand => Discard) }).is_err());
+    assert!(!game.is_revealed(&hand2, uuid1));
+    assert_eq!(game.view_for(uuid1).unwrap().player(uuid2).unwrap().hand, None);
+
+    // A Nanny Cam makes the hand visible to everybody
+    let card = card!(game.uuids(), NannyCam);
+    game.p_mut(uuid2).stable_mut().add_card(card);
+    assert_eq!(game.view_for(uuid1).unwrap().player(uuid2).unwrap().hand.as_ref().unwrap().len(), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn test_save_load() {
+    let mut game = Game::setup(&["Alice", "Bob"], DeckSpec::Base).unwrap();
+
+    for player_uuid in game.player_uuids() {
+        game.set_decider(player_uuid, Box::new(PassiveDecider));
+    }
+
+    for _ in 0..3 {
+        assert_eq!(game.run_turn().unwrap(), ());
+    }
+
+    // A card that remembers what it stole
+    let alice = game.seats()[0];
+    let bob = game.seats()[1];
+    let baby_uuid = game.p(bob).stable().uuids()[0];
+    game.p_mut(alice).stable_mut().add_card(Box::new(cards::UnicornLasso::with_state(CardUuid(1337), Some(bob), Some(baby_uuid))));
+
+    let check = |loaded: &Game| {
+        assert_eq!(loaded.seats(), game.seats());
+        assert_eq!(loaded.turn(), game.turn());
+        assert_eq!(loaded.phase(), game.phase());
+        assert_eq!(loaded.log(), game.log());
+        assert_eq!(loaded.table.drawpile.uuids(), game.table.drawpile.uuids());
+        assert_eq!(loaded.table.discard.uuids(), game.table.discard.uuids());
+        assert_eq!(loaded.table.nursery.uuids(), game.table.nursery.uuids());
+
+        for &player_uuid in game.seats() {
+            assert_eq!(format!("{:?}", loaded.p(player_uuid)), format!("{:?}", game.p(player_uuid)));
+        }
+
+        let lasso = loaded.p(alice).stable().get_card(CardUuid(1337)).unwrap();
+        assert_eq!(lasso.state(), CardState::Steal { target_uuid: Some(bob), stolen_card_uuid: Some(baby_uuid) });
+    };
+
+    let json = game.to_json().unwrap();
+    check(&Game::from_json(&json).unwrap());
+
+    let bytes = game.to_bytes().unwrap();
+    check(&Game::from_bytes(&bytes).unwrap());
+
+    // The loaded game goes on exactly like the saved one, once the deciders are set again
+    let mut loaded = Game::from_bytes(&bytes).unwrap();
+    for player_uuid in loaded.player_uuids() {
+        loaded.set_decider(player_uuid, Box::new(PassiveDecider));
+    }
+
+    assert_eq!(loaded.run_turn().unwrap(), ());
+    assert_eq!(game.run_turn().unwrap(), ());
+    assert_eq!(loaded.log(), game.log());
+    assert_eq!(loaded.table.drawpile.uuids(), game.table.drawpile.uuids());
+
+    // A card can't be loaded with a state that doesn't belong to it
+    let broken = json.replacen("\"Stateless\"", "{\"Baby\":\"Narwhal\"}", 1);
+    assert!(matches!(Game::from_json(&broken), Err(GameError::InvalidSave { .. })));
+    assert!(matches!(Game::from_bytes(&bytes[..10]), Err(GameError::InvalidSave { .. })));
+}
@@ -1,56 +1,73 @@
-use crate::{Card, CardType};
-
-use rand::Rng;
+use crate::{Action, Card, CardID, CardType, CardUuid, Decider, GameError};
 
 /// Make a user choose n items from a list
-pub fn user_choose_n<'a, T>(_question: &'static str, items: &'a Vec<T>, n: usize) -> Vec<&'a T> {
-    assert!(items.len() >= n);
+/// This gives an error if there aren't enough items or if the decider gives an invalid answer
+pub fn user_choose_n<T: Copy + Into<u64>>(decider: &mut dyn Decider, question: &'static str, items: &[T], n: usize) -> Result<Vec<T>, GameError> {
+    if items.len() < n {
+        return Err(GameError::NotAllowed { reason: "Not enough items to choose from" });
+    }
 
-    // @TODO: Make user actually able to choose
-    (0..n).into_iter().map(|i| &items[i]).collect()
-}
+    let options: Vec<u64> = items.iter().map(|&item| item.into()).collect();
+    let mut indices = decider.choose_n(question, &options, n);
 
-/// Make a user choose n items from a list and copy value
-pub fn user_choose_n_copy<T: Copy>(_question: &'static str, items: &Vec<T>, n: usize) -> Vec<T> {
-    assert!(items.len() >= n);
+    // Make sure the decider didn't cheat, by choosing an unknown item or the same item several times
+    indices.sort_unstable();
+    indices.dedup();
+    if indices.len() != n || indices.iter().any(|&i| i >= items.len()) {
+        return Err(GameError::InvalidChoice { question });
+    }
 
-    // @TODO: Make user actually able to choose
-    (0..n).into_iter().map(|i| items[i]).collect()
+    Ok(indices.into_iter().map(|i| items[i]).collect())
 }
 
 /// Make a user choose from a list of items
-pub fn user_choose<'a, T>(_question: &'static str, items: &'a Vec<T>) -> &'a T {
-    assert!(!items.is_empty());
+/// This gives an error if there are no items or if the decider gives an invalid answer
+pub fn user_choose<T: Copy + Into<u64>>(decider: &mut dyn Decider, question: &'static str, items: &[T]) -> Result<T, GameError> {
+    if items.is_empty() {
+        return Err(GameError::NotAllowed { reason: "Not enough items to choose from" });
+    }
 
-    // @TODO: Make user actually able to choose
-    &items[0]
+    let options: Vec<u64> = items.iter().map(|&item| item.into()).collect();
+    let index = decider.choose(question, &options);
+
+    // Make sure the decider didn't cheat
+    items.get(index).copied().ok_or(GameError::InvalidChoice { question })
+}
+
+/// Make a user choose the order in which the effects of their cards that triggered at the same moment resolve
+/// Cards that didn't trigger anything are left out, and the user is only asked when there is a choice
+pub fn user_order_triggers(decider: &mut dyn Decider, mut triggers: Vec<(CardUuid, Vec<Action>)>) -> Result<Vec<Action>, GameError> {
+    triggers.retain(|(_, actions)| !actions.is_empty());
+
+    let mut actions = vec!{};
+
+    while !triggers.is_empty() {
+        let index = if triggers.len() == 1 {
+            0
+        } else {
+            let card_uuids: Vec<CardUuid> = triggers.iter().map(|(card_uuid, _)| *card_uuid).collect();
+            let card_uuid = user_choose(decider, "Which card's effect do you want to resolve first?", &card_uuids)?;
+
+            card_uuids.iter().position(|&x| x == card_uuid).unwrap()  // CONFIRMED SAFE UNWRAP
+        };
+
+        actions.append(&mut triggers.remove(index).1);
+    }
+
+    Ok(actions)
 }
 
 /// Make a user choose from a yes/no question
-pub fn user_choose_bool(question: &'static str) -> bool {
-    *user_choose(question, &vec!{true, false})
+pub fn user_choose_bool(decider: &mut dyn Decider, question: &'static str) -> bool {
+    decider.choose_bool(question)
 }
 
-static mut NEXT_UUID: u64 = 0;
-
-/// Generate a random uuid
-/// WILL ALWAYS BE >= 1
-pub fn uuid() -> u64 {
-    // To prevent the "unused rand::Rng" message
-    if unsafe { NEXT_UUID } > 10000 {
-        ::rand::thread_rng().gen::<u64>()
-    } else {
-        unsafe {
-            NEXT_UUID += 1;
-            NEXT_UUID
-        }
-    }
+/// Check if a card is a Neigh that can stop other cards from being played
+pub fn is_neigh(card: &dyn Card) -> bool {
+    card.id() == CardID::Neigh || card.id() == CardID::SuperNeigh
 }
 
 /// Check if a card is a unicorn
 pub fn is_unicorn(card: &dyn Card) -> bool {
-    match card.cardtype() {
-        CardType::Unicorn(_) => true,
-        _ => false
-    }
-}
\ No newline at end of file
+    matches!(card.cardtype(), CardType::Unicorn(_))
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// The UUID of a card, which is unique within a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct CardUuid(pub u64);
+
+impl CardUuid {
+    /// Taking this card from the drawpile takes a random card instead
+    pub const RANDOM: CardUuid = CardUuid(0);
+}
+
+/// The UUID of a player, which is unique within a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct PlayerUuid(pub u64);
+
+/// Both UUIDs are written as plain numbers (eg. in the event log)
+macro_rules! impl_uuid {
+    ($type:ident) => {
+        impl From<$type> for u64 {
+            fn from(uuid: $type) -> u64 {
+                uuid.0
+            }
+        }
+
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<$type, ParseIntError> {
+                s.parse().map($type)
+            }
+        }
+    };
+}
+
+impl_uuid!(CardUuid);
+impl_uuid!(PlayerUuid);
+
+/// Hands out the UUIDs of a single game
+/// The UUIDs start at 1 and count up, so every game with the same setup gets the same UUIDs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UuidAllocator {
+    last_card: u64,
+    last_player: u64,
+}
+
+impl UuidAllocator {
+    pub fn new() -> UuidAllocator {
+        UuidAllocator::default()
+    }
+
+    /// Get the UUID for a new card
+    /// WILL ALWAYS BE >= 1
+    pub fn card(&mut self) -> CardUuid {
+        self.last_card += 1;
+        CardUuid(self.last_card)
+    }
+
+    /// Get the UUID for a new player
+    pub fn player(&mut self) -> PlayerUuid {
+        self.last_player += 1;
+        PlayerUuid(self.last_player)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{CardID, CardUuid, GameOutcome, Phase, PlayerUuid};
+
+/// What a single player may know about the game, see Game::view_for
+/// Network clients and bots should only ever get to see views, never the game itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerView {
+    /// The player this view is for
+    pub player_uuid: PlayerUuid,
+
+    /// What can be seen of every player, in seating order
+    pub players: Vec<PlayerInfo>,
+
+    /// The cards in the discard pile, which everybody can see
+    pub discard: Vec<VisibleCard>,
+
+    /// The number of cards in the nursery
+    pub nursery_size: usize,
+
+    /// The number of cards in the drawpile, whose order nobody may know
+    pub drawpile_size: usize,
+
+    /// The number of the current turn
+    pub turn: u64,
+
+    /// The player whose turn it is
+    pub current_player_uuid: Option<PlayerUuid>,
+
+    /// The phase of the current turn
+    pub phase: Option<Phase>,
+
+    /// The outcome of the game, once somebody has won
+    pub outcome: Option<GameOutcome>,
+}
+
+impl PlayerView {
+    /// Get what can be seen of the given player
+    pub fn player(&self, player_uuid: PlayerUuid) -> Option<&PlayerInfo> {
+        self.players.iter().find(|player| player.uuid == player_uuid)
+    }
+
+    /// Get the hand of the player this view is for
+    pub fn hand(&self) -> &[VisibleCard] {
+        self.player(self.player_uuid).and_then(|player| player.hand.as_deref()).unwrap_or_default()
+    }
+}
+
+/// What can be seen of a single player
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerInfo {
+    pub uuid: PlayerUuid,
+    pub name: String,
+
+    /// The cards in the player's stable, which everybody can see
+    pub stable: Vec<VisibleCard>,
+
+    /// The number of cards in the player's hand
+    pub hand_size: usize,
+
+    /// The cards in the player's hand, if the viewer may see them
+    /// This is the viewer's own hand, a hand that is always visible (eg. with a Nanny Cam)
+    /// or a hand that has been revealed to the viewer this turn (eg. by a Blatant Thievery)
+    pub hand: Option<Vec<VisibleCard>>,
+}
+
+/// A card that can be seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VisibleCard {
+    pub uuid: CardUuid,
+    pub id: CardID,
+}